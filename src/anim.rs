@@ -0,0 +1,124 @@
+use vec2d::Vec2D;
+
+/// Moves `current` towards `target` like a critically damped spring, reaching it in roughly `smooth_time` seconds without overshooting
+/// (in the style of Unity's `SmoothDamp`). `velocity` carries the motion between calls and is updated in place; start it at zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::anim::smooth_damp;
+/// 	let mut velocity = Vec2D::new(0f64, 0f64);
+/// 	let next = smooth_damp(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64), &mut velocity, 0.5, 1f64 / 60f64);
+/// 	assert!(next.x > 0f64 && next.x < 10f64);
+/// 	assert!(velocity.x > 0f64);
+/// ```
+pub fn smooth_damp(current: Vec2D<f64>, target: Vec2D<f64>, velocity: &mut Vec2D<f64>, smooth_time: f64, dt: f64) -> Vec2D<f64> {
+	let omega = 2f64 / smooth_time.max(1e-4);
+	// a cheap, accurate approximation of exp(-omega * dt)
+	let x = omega * dt;
+	let decay = 1f64 / (1f64 + x + 0.48 * x * x + 0.235 * x * x * x);
+	let change = current - target;
+	let temp = (*velocity + change * omega) * dt;
+	*velocity = (*velocity - temp * omega) * decay;
+	let output = target + (change + temp) * decay;
+	// never pass the target
+	if Vec2D::dot_product(target - current, output - target) > 0f64 {
+		*velocity = Vec2D::default();
+		return target;
+	}
+	output
+}
+
+/// a track of `(time, position)` keyframes, sampled by interpolating linearly between the keyframes either side of a time
+/// Note: `keyframes` should be sorted by time, as `new` leaves them
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct AnimationTrack {
+	pub keyframes: Vec<(f64, Vec2D<f64>)>
+}
+
+impl AnimationTrack {
+	/// Creates a track from keyframes in any order, sorting them by time.
+	pub fn new(mut keyframes: Vec<(f64, Vec2D<f64>)>) -> AnimationTrack {
+		keyframes.sort_by(|a, b| a.0.total_cmp(&b.0));
+		AnimationTrack{keyframes}
+	}
+
+	/// The position at `time`, interpolated between the surrounding keyframes with `lerp`.
+	/// Times before the first keyframe or after the last are clamped to it; an empty track is always at the origin.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::anim::AnimationTrack;
+	/// 	let track = AnimationTrack::new(vec![(0f64, Vec2D::new(0f64, 0f64)), (2f64, Vec2D::new(4f64, 2f64))]);
+	/// 	assert_eq!(track.sample(0.5), Vec2D::new(1f64, 0.5));
+	/// 	assert_eq!(track.sample(-1f64), Vec2D::new(0f64, 0f64));
+	/// ```
+	pub fn sample(&self, time: f64) -> Vec2D<f64> {
+		let keyframes = &self.keyframes;
+		let next = keyframes.partition_point(|&(t, _)| t <= time);
+		if next == 0 {
+			return keyframes.first().map_or(Vec2D::default(), |&(_, position)| position);
+		}
+		if next == keyframes.len() {
+			return keyframes[next - 1].1;
+		}
+		let ((t0, from), (t1, to)) = (keyframes[next - 1], keyframes[next]);
+		from.lerp(to, (time - t0) / (t1 - t0))
+	}
+}
+
+/// the state of a damped spring pulling a point towards a target, for bouncy motion
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct SpringState {
+	pub position: Vec2D<f64>,
+	pub velocity: Vec2D<f64>
+}
+
+impl SpringState {
+	/// Creates a spring at rest at `position`.
+	pub fn new(position: Vec2D<f64>) -> SpringState {
+		SpringState{position, ..SpringState::default()}
+	}
+
+	/// Advances the spring by `dt` as a damped harmonic oscillator (with unit mass) pulled towards `target`: the acceleration is
+	/// `stiffness * (target - position) - damping * velocity`, integrated with semi-implicit Euler.
+	/// A `damping` of `2 * stiffness.sqrt()` is critically damped; less overshoots and bounces, more eases in slowly.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::anim::SpringState;
+	/// 	let mut spring = SpringState::new(Vec2D::new(0f64, 0f64));
+	/// 	spring.update(Vec2D::new(1f64, 0f64), 100f64, 5f64, 0.01);
+	/// 	assert_eq!(spring.velocity, Vec2D::new(1f64, 0f64));
+	/// 	assert_eq!(spring.position, Vec2D::new(0.01, 0f64));
+	/// ```
+	pub fn update(&mut self, target: Vec2D<f64>, stiffness: f64, damping: f64, dt: f64) {
+		let acceleration = (target - self.position) * stiffness - self.velocity * damping;
+		self.velocity += acceleration * dt;
+		self.position += self.velocity * dt;
+	}
+}
+
+/// how `interpolate` blends between two vectors
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InterpMode {
+	/// straight-line blending (`Vec2D::lerp`), for positions
+	Linear,
+	/// blending around the arc between them (`Vec2D::slerp`), for directions
+	Spherical
+}
+
+/// Interpolates from `a` (at `t = 0`) to `b` (at `t = 1`) using the interpolation chosen by `mode`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::anim::{interpolate, InterpMode};
+/// 	let (a, b) = (Vec2D::new(2f64, 0f64), Vec2D::new(0f64, 2f64));
+/// 	assert_eq!(interpolate(a, b, 0.5, InterpMode::Linear), Vec2D::new(1f64, 1f64));
+/// 	assert!((interpolate(a, b, 0.5, InterpMode::Spherical).magnitude() - 2f64).abs() < 1e-12);
+/// ```
+pub fn interpolate(a: Vec2D<f64>, b: Vec2D<f64>, t: f64, mode: InterpMode) -> Vec2D<f64> {
+	match mode {
+		InterpMode::Linear => a.lerp(b, t),
+		InterpMode::Spherical => a.slerp(b, t)
+	}
+}
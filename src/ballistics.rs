@@ -0,0 +1,62 @@
+use vec2d::Vec2D;
+
+/// Samples the path of a projectile launched from `start` with `velocity` under constant `gravity` (an acceleration),
+/// returning `steps + 1` positions `dt` seconds apart, beginning with `start`. Points are computed exactly from the equations of motion
+/// rather than by stepping a simulation, so they don't drift.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::ballistics::predict_trajectory;
+/// 	let path = predict_trajectory(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 10f64), Vec2D::new(0f64, -10f64), 2, 1f64);
+/// 	assert_eq!(path, vec![Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 5f64), Vec2D::new(4f64, 0f64)]);
+/// ```
+pub fn predict_trajectory(start: Vec2D<f64>, velocity: Vec2D<f64>, gravity: Vec2D<f64>, steps: usize, dt: f64) -> Vec<Vec2D<f64>> {
+	(0..steps + 1).map(|i| {
+		let t = i as f64 * dt;
+		start + velocity * t + gravity * (0.5 * t * t)
+	}).collect()
+}
+
+/// Solves the artillery aiming problem: the launch velocities of magnitude `speed` that carry a projectile from `start` to `target`
+/// under gravity of strength `gravity` pulling in the `-y` direction. Returns the `(low, high)` arcs (equal when the target is at the edge of range),
+/// or `None` if the target is out of range at this speed.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::ballistics::launch_velocity;
+/// 	// at the maximum range of v^2 / g, the only solution is the 45 degree launch
+/// 	let (low, high) = launch_velocity(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64), 10f64, 10f64).unwrap();
+/// 	assert!((low - Vec2D::new(50f64.sqrt(), 50f64.sqrt())).magnitude() < 1e-6);
+/// 	assert!((high - low).magnitude() < 1e-6);
+/// ```
+pub fn launch_velocity(start: Vec2D<f64>, target: Vec2D<f64>, speed: f64, gravity: f64) -> Option<(Vec2D<f64>, Vec2D<f64>)> {
+	let offset = target - start;
+	if offset == Vec2D::default() || speed <= 0f64 {
+		return None;
+	}
+	if gravity == 0f64 {
+		let direct = offset.to_unit() * speed;
+		return Some((direct, direct));
+	}
+	let (x, y) = (offset.x.abs(), offset.y);
+	let v2 = speed * speed;
+	if x == 0f64 {
+		// straight up or down; firing upwards also comes back down through any point below
+		return if y > 0f64 {
+			if v2 >= 2f64 * gravity * y { Some((Vec2D::new(0f64, speed), Vec2D::new(0f64, speed))) } else { None }
+		} else {
+			Some((Vec2D::new(0f64, -speed), Vec2D::new(0f64, speed)))
+		};
+	}
+	let discriminant = v2 * v2 - gravity * (gravity * x * x + 2f64 * y * v2);
+	if discriminant < 0f64 {
+		return None;
+	}
+	let root = discriminant.sqrt();
+	let heading = offset.x.signum();
+	let velocity_at = |tan: f64| {
+		let angle = tan.atan();
+		Vec2D::new(angle.cos() * speed * heading, angle.sin() * speed)
+	};
+	Some((velocity_at((v2 - root) / (gravity * x)), velocity_at((v2 + root) / (gravity * x))))
+}
@@ -0,0 +1,37 @@
+use shapes::AABB;
+
+/// Finds the pairs of overlapping `boxes` (touching counts, as with `AABB::intersects`) by sorting them along the x axis
+/// and sweeping across, so only boxes whose x extents overlap are ever compared.
+/// Each pair is given as `(i, j)` indices with `i < j`, in order.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::broadphase::sweep_and_prune;
+/// 	let boxes = [
+/// 		AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 2f64)),
+/// 		AABB::new(Vec2D::new(5f64, 0f64), Vec2D::new(6f64, 1f64)),
+/// 		AABB::new(Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 3f64))
+/// 	];
+/// 	assert_eq!(sweep_and_prune(&boxes), vec![(0, 2)]);
+/// ```
+pub fn sweep_and_prune(boxes: &[AABB<f64>]) -> Vec<(usize, usize)> {
+	let mut order: Vec<usize> = (0..boxes.len()).collect();
+	order.sort_by(|&a, &b| boxes[a].min.x.total_cmp(&boxes[b].min.x));
+
+	let mut pairs = Vec::new();
+	let mut active: Vec<usize> = Vec::new();
+	for &index in order.iter() {
+		let current = &boxes[index];
+		// anything ending before this box starts can't overlap it or any box after it
+		active.retain(|&other| boxes[other].max.x >= current.min.x);
+		for &other in active.iter() {
+			if current.intersects(&boxes[other]) {
+				pairs.push((index.min(other), index.max(other)));
+			}
+		}
+		active.push(index);
+	}
+	pairs.sort();
+	pairs
+}
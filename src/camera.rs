@@ -0,0 +1,119 @@
+use vec2d::Vec2D;
+use shapes::AABB;
+use noise::value_noise_2d;
+
+/// Culling check: whether `point` lies within `viewport`, grown by `margin` on every side
+/// (e.g. so that a sprite centred just off-screen whose extents still overlap the viewport is kept).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::camera::is_visible;
+/// 	let viewport = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(800f64, 600f64));
+/// 	assert!(is_visible(Vec2D::new(400f64, 300f64), &viewport, 0f64));
+/// 	assert!(is_visible(Vec2D::new(-10f64, 300f64), &viewport, 16f64));
+/// ```
+pub fn is_visible(point: Vec2D<f64>, viewport: &AABB<f64>, margin: f64) -> bool {
+	viewport.expanded(margin).contains(point)
+}
+
+/// a 2D game camera looking at `position` in the world, magnified by `zoom`, onto a screen of `viewport_size` pixels
+/// Note: screen coordinates have their origin at the viewport's corner and keep the world's axis directions (no y flip)
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Camera2D {
+	pub position: Vec2D<f64>,
+	pub zoom: f64,
+	pub viewport_size: Vec2D<f64>
+}
+
+impl Camera2D {
+	/// Creates a camera centred on `position`.
+	pub fn new(position: Vec2D<f64>, zoom: f64, viewport_size: Vec2D<f64>) -> Camera2D {
+		Camera2D{position, zoom, viewport_size}
+	}
+
+	/// Maps a world-space point to its screen-space position.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::camera::Camera2D;
+	/// 	let camera = Camera2D::new(Vec2D::new(10f64, 10f64), 2f64, Vec2D::new(800f64, 600f64));
+	/// 	assert_eq!(camera.world_to_screen(Vec2D::new(10f64, 10f64)), Vec2D::new(400f64, 300f64));
+	/// 	assert_eq!(camera.world_to_screen(Vec2D::new(15f64, 10f64)), Vec2D::new(410f64, 300f64));
+	/// ```
+	pub fn world_to_screen(&self, world: Vec2D<f64>) -> Vec2D<f64> {
+		(world - self.position) * self.zoom + self.viewport_size / 2f64
+	}
+
+	/// Maps a screen-space position back to the world-space point beneath it; the inverse of `world_to_screen`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::camera::Camera2D;
+	/// 	let camera = Camera2D::new(Vec2D::new(10f64, 10f64), 2f64, Vec2D::new(800f64, 600f64));
+	/// 	assert_eq!(camera.screen_to_world(Vec2D::new(410f64, 300f64)), Vec2D::new(15f64, 10f64));
+	/// ```
+	pub fn screen_to_world(&self, screen: Vec2D<f64>) -> Vec2D<f64> {
+		(screen - self.viewport_size / 2f64) / self.zoom + self.position
+	}
+
+	/// Eases the camera towards `target` using exponential smoothing, where `smoothing` is the rate (per unit time) at which the remaining distance decays.
+	/// Frame-rate independent: two steps of `dt` land in the same place as one step of `2*dt`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::camera::Camera2D;
+	/// 	let mut camera = Camera2D::new(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(800f64, 600f64));
+	/// 	camera.follow(Vec2D::new(100f64, 0f64), 2f64, std::f64::consts::LN_2 / 2f64);
+	/// 	assert!((camera.position.x - 50f64).abs() < 1e-9);
+	/// ```
+	pub fn follow(&mut self, target: Vec2D<f64>, smoothing: f64, dt: f64) {
+		let blend = 1f64 - (-smoothing * dt).exp();
+		self.position += (target - self.position) * blend;
+	}
+}
+
+/// trauma-based screen shake: `trauma` in `[0,1]` is added to on impacts and decays by `decay` per unit time,
+/// while the shake offset scales with trauma squared so small knocks stay subtle
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct ScreenShake {
+	pub trauma: f64,
+	pub decay: f64,
+	/// how quickly (in noise lattice cells per unit time) the shake offset wanders
+	pub frequency: f64,
+	seed: u32,
+	time: f64
+}
+
+impl ScreenShake {
+	/// Creates a shake with no trauma yet; `seed` selects the (deterministic) shake pattern.
+	pub fn new(decay: f64, frequency: f64, seed: u32) -> ScreenShake {
+		ScreenShake{trauma: 0f64, decay, frequency, seed, time: 0f64}
+	}
+
+	/// Adds trauma (e.g. on an explosion), saturating at 1.
+	pub fn add_trauma(&mut self, amount: f64) {
+		self.trauma = (self.trauma + amount).clamp(0f64, 1f64);
+	}
+
+	/// Produces this frame's camera offset, at most `max_offset` on each axis at full trauma, then advances time and decays the trauma by `dt`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::camera::ScreenShake;
+	/// 	let mut shake = ScreenShake::new(1f64, 15f64, 3);
+	/// 	shake.add_trauma(0.5);
+	/// 	let offset = shake.offset(0.1, Vec2D::new(20f64, 10f64));
+	/// 	assert!(offset.x.abs() <= 5f64 && offset.y.abs() <= 2.5);
+	/// 	assert!((shake.trauma - 0.4).abs() < 1e-12);
+	/// ```
+	pub fn offset(&mut self, dt: f64, max_offset: Vec2D<f64>) -> Vec2D<f64> {
+		let shake = self.trauma * self.trauma;
+		let t = self.time * self.frequency;
+		let sample = |seed: u32| 2f64 * value_noise_2d(Vec2D::new(t, 0f64), seed) - 1f64;
+		let offset = Vec2D::new(max_offset.x * shake * sample(self.seed), max_offset.y * shake * sample(self.seed.wrapping_add(1)));
+		self.time += dt;
+		self.trauma = (self.trauma - self.decay * dt).max(0f64);
+		offset
+	}
+}
@@ -0,0 +1,212 @@
+use vec2d::Vec2D;
+use polygon::{counter_clockwise, point_in_polygon};
+use shapes::AABB;
+
+// Cohen-Sutherland outcode bits, for which sides of the window a point lies beyond
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BELOW: u8 = 4;
+const ABOVE: u8 = 8;
+
+fn outcode(point: Vec2D<f64>, window: &AABB<f64>) -> u8 {
+	let mut code = 0;
+	if point.x < window.min.x { code |= LEFT; } else if point.x > window.max.x { code |= RIGHT; }
+	if point.y < window.min.y { code |= BELOW; } else if point.y > window.max.y { code |= ABOVE; }
+	code
+}
+
+/// where the segments `a0`-`a1` and `b0`-`b1` properly cross, as the parameters along each (both strictly within `(0, 1)`)
+fn segment_crossing(a0: Vec2D<f64>, a1: Vec2D<f64>, b0: Vec2D<f64>, b1: Vec2D<f64>) -> Option<(f64, f64)> {
+	let (da, db) = (a1 - a0, b1 - b0);
+	let denominator = Vec2D::cross_product(da, db);
+	if denominator == 0f64 {
+		return None;
+	}
+	let offset = b0 - a0;
+	let s = Vec2D::cross_product(offset, db) / denominator;
+	let t = Vec2D::cross_product(offset, da) / denominator;
+	if s > 0f64 && s < 1f64 && t > 0f64 && t < 1f64 { Some((s, t)) } else { None }
+}
+
+/// a vertex of a polygon's outline with the crossings spliced in, noting which crossing (if any) it is
+#[derive(Copy, Clone, Debug)]
+struct Node {
+	point: Vec2D<f64>,
+	crossing: Option<usize>
+}
+
+/// the polygon's outline with each crossing inserted into its edge (in order along it),
+/// along with where in the outline each crossing ended up
+fn splice_crossings(polygon: &[Vec2D<f64>], crossings: &[(usize, f64)]) -> (Vec<Node>, Vec<usize>) {
+	let mut nodes = Vec::with_capacity(polygon.len() + crossings.len());
+	let mut positions = vec![0; crossings.len()];
+	for (edge, &vertex) in polygon.iter().enumerate() {
+		nodes.push(Node{point: vertex, crossing: None});
+		let mut on_edge: Vec<usize> = (0..crossings.len()).filter(|&k| crossings[k].0 == edge).collect();
+		on_edge.sort_by(|&x, &y| crossings[x].1.total_cmp(&crossings[y].1));
+		for k in on_edge {
+			let next = polygon[(edge + 1) % polygon.len()];
+			positions[k] = nodes.len();
+			nodes.push(Node{point: vertex + (next - vertex) * crossings[k].1, crossing: Some(k)});
+		}
+	}
+	(nodes, positions)
+}
+
+/// Computes the union of two simple polygons (in either winding order) by splicing the points where their edges cross into both
+/// outlines and then tracing around them Weiler-Atherton style, switching outline at each crossing to stay on the outside.
+/// The outer outlines come back counter-clockwise; any holes enclosed between the two shapes come back as clockwise outlines.
+/// Polygons that don't cross give both polygons (counter-clockwise), or just the outer one if one lies inside the other.
+/// Note: only handles polygons in general position; edges that overlap along a line or cross exactly at vertices aren't detected as crossings.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::clip::polygon_union;
+/// 	use phys2d::polygon::signed_area;
+/// 	let a = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let b = [Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 1f64), Vec2D::new(3f64, 3f64), Vec2D::new(1f64, 3f64)];
+/// 	let union = polygon_union(&a, &b);
+/// 	assert_eq!(union.len(), 1);
+/// 	assert_eq!(signed_area(&union[0]), 7f64);
+/// ```
+pub fn polygon_union(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> Vec<Vec<Vec2D<f64>>> {
+	let (a, b) = (counter_clockwise(a), counter_clockwise(b));
+	let (mut along_a, mut along_b) = (Vec::new(), Vec::new());
+	for i in 0..a.len() {
+		for j in 0..b.len() {
+			if let Some((s, t)) = segment_crossing(a[i], a[(i + 1) % a.len()], b[j], b[(j + 1) % b.len()]) {
+				along_a.push((i, s));
+				along_b.push((j, t));
+			}
+		}
+	}
+	if along_a.is_empty() {
+		if !b.is_empty() && point_in_polygon(b[0], &a) {
+			return vec![a];
+		}
+		if !a.is_empty() && point_in_polygon(a[0], &b) {
+			return vec![b];
+		}
+		return vec![a, b];
+	}
+
+	let outlines = [splice_crossings(&a, &along_a), splice_crossings(&b, &along_b)];
+	let shapes = [&a, &b];
+	let mut visited = vec![false; along_a.len()];
+	let mut result = Vec::new();
+	for start in 0..visited.len() {
+		if visited[start] {
+			continue;
+		}
+		// leave the crossing along whichever outline heads outside the other shape
+		let heads_outside = |outline: usize| {
+			let nodes = &outlines[outline].0;
+			let position = outlines[outline].1[start];
+			let midpoint = (nodes[position].point + nodes[(position + 1) % nodes.len()].point) / 2f64;
+			!point_in_polygon(midpoint, shapes[1 - outline])
+		};
+		let mut outline = if heads_outside(0) { 0 } else { 1 };
+		let mut position = outlines[outline].1[start];
+		let mut traced = Vec::new();
+		visited[start] = true;
+		let limit = outlines[0].0.len() + outlines[1].0.len();
+		// the length limit only guards against degenerate input looping forever
+		while traced.len() <= limit {
+			let nodes = &outlines[outline].0;
+			traced.push(nodes[position].point);
+			position = (position + 1) % nodes.len();
+			if let Some(crossing) = nodes[position].crossing {
+				if crossing == start {
+					break;
+				}
+				visited[crossing] = true;
+				outline = 1 - outline;
+				position = outlines[outline].1[crossing];
+			}
+		}
+		result.push(traced);
+	}
+	result
+}
+
+/// Clips the `subject` polygon to the inside of a convex `clip` polygon (either may be in either winding order) using the
+/// Sutherland-Hodgman algorithm, cutting it against each of the clip polygon's edges in turn.
+/// The result keeps the subject's winding order, and is empty if the polygons don't overlap.
+/// Note: clipping a concave subject can leave zero-width bridges along the clip edges where it split into several pieces.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::clip::clip_polygon;
+/// 	let window = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let overlapping = [Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 1f64), Vec2D::new(3f64, 3f64), Vec2D::new(1f64, 3f64)];
+/// 	let clipped = clip_polygon(&overlapping, &window);
+/// 	assert_eq!(clipped, vec![Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 1f64), Vec2D::new(2f64, 2f64), Vec2D::new(1f64, 2f64)]);
+/// ```
+pub fn clip_polygon(subject: &[Vec2D<f64>], clip: &[Vec2D<f64>]) -> Vec<Vec2D<f64>> {
+	let clip = counter_clockwise(clip);
+	let mut output = subject.to_vec();
+	for i in 0..clip.len() {
+		if output.is_empty() {
+			break;
+		}
+		let (edge_start, edge_end) = (clip[i], clip[(i + 1) % clip.len()]);
+		let edge = edge_end - edge_start;
+		let side = |p: Vec2D<f64>| Vec2D::cross_product(edge, p - edge_start);
+		let input = std::mem::take(&mut output);
+		for j in 0..input.len() {
+			let (current, next) = (input[j], input[(j + 1) % input.len()]);
+			let (current_side, next_side) = (side(current), side(next));
+			if current_side >= 0f64 {
+				output.push(current);
+			}
+			if (current_side >= 0f64) != (next_side >= 0f64) {
+				output.push(current + (next - current) * (current_side / (current_side - next_side)));
+			}
+		}
+	}
+	output
+}
+
+/// Clips the line segment from `a` to `b` to the `window` (boundary included) using the Cohen-Sutherland algorithm,
+/// repeatedly cutting off the part beyond whichever window edge an outside endpoint lies past.
+/// Returns the clipped segment (in the same direction), or `None` if none of it lies within the window.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::clip::clip_line;
+/// 	let window = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 10f64));
+/// 	assert_eq!(clip_line(Vec2D::new(-5f64, 5f64), Vec2D::new(5f64, 5f64), &window), Some((Vec2D::new(0f64, 5f64), Vec2D::new(5f64, 5f64))));
+/// 	assert_eq!(clip_line(Vec2D::new(-5f64, 5f64), Vec2D::new(5f64, 20f64), &window), None);
+/// ```
+pub fn clip_line(a: Vec2D<f64>, b: Vec2D<f64>, window: &AABB<f64>) -> Option<(Vec2D<f64>, Vec2D<f64>)> {
+	let (mut a, mut b) = (a, b);
+	let (mut code_a, mut code_b) = (outcode(a, window), outcode(b, window));
+	loop {
+		if code_a | code_b == 0 {
+			return Some((a, b));
+		}
+		if code_a & code_b != 0 {
+			return None;
+		}
+		// move an outside endpoint onto the edge it lies beyond; the division is safe as the segment crosses that edge's line
+		let code = if code_a != 0 { code_a } else { code_b };
+		let delta = b - a;
+		let point = if code & ABOVE != 0 {
+			Vec2D::new(a.x + delta.x * (window.max.y - a.y) / delta.y, window.max.y)
+		} else if code & BELOW != 0 {
+			Vec2D::new(a.x + delta.x * (window.min.y - a.y) / delta.y, window.min.y)
+		} else if code & RIGHT != 0 {
+			Vec2D::new(window.max.x, a.y + delta.y * (window.max.x - a.x) / delta.x)
+		} else {
+			Vec2D::new(window.min.x, a.y + delta.y * (window.min.x - a.x) / delta.x)
+		};
+		if code == code_a {
+			a = point;
+			code_a = outcode(a, window);
+		} else {
+			b = point;
+			code_b = outcode(b, window);
+		}
+	}
+}
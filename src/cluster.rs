@@ -0,0 +1,114 @@
+use vec2d::Vec2D;
+use polygon::centroid;
+use noise::lattice_value;
+use kdtree::KdTree2D;
+
+/// the index of the centre nearest to `point`
+fn nearest_center(point: Vec2D<f64>, centers: &[Vec2D<f64>]) -> usize {
+	(0..centers.len()).fold(0, |best, i| if point.distance_squared(centers[i]) < point.distance_squared(centers[best]) { i } else { best })
+}
+
+/// Partitions `points` into `k` clusters with Lloyd's algorithm, returning the cluster index (in `0..k`) of each point.
+/// The initial centres are chosen by k-means++ (each new centre picked with probability proportional to its squared distance
+/// from the nearest centre so far), driven by `seed` so the result is reproducible. Then, for up to `max_iters` rounds, each point
+/// is assigned to its nearest centre and each centre moved to the `centroid` of its points, stopping early once nothing changes.
+/// `k` is clamped to between one and the number of points.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::cluster::kmeans;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 10f64), Vec2D::new(0.5, 0f64), Vec2D::new(10f64, 9.5)];
+/// 	let clusters = kmeans(&points, 2, 10, 1);
+/// 	assert_eq!(clusters[0], clusters[2]);
+/// 	assert_eq!(clusters[1], clusters[3]);
+/// 	assert_ne!(clusters[0], clusters[1]);
+/// ```
+pub fn kmeans(points: &[Vec2D<f64>], k: usize, max_iters: usize, seed: u32) -> Vec<usize> {
+	if points.is_empty() {
+		return Vec::new();
+	}
+	let k = k.clamp(1, points.len());
+	let pick = |draw: i32, range: f64| lattice_value(draw, 0, seed) * range;
+
+	let first = (pick(0, points.len() as f64) as usize).min(points.len() - 1);
+	let mut centers = vec![points[first]];
+	while centers.len() < k {
+		let weights: Vec<f64> = points.iter().map(|&p| p.distance_squared(centers[nearest_center(p, &centers)])).collect();
+		let total: f64 = weights.iter().sum();
+		if total == 0f64 {
+			// every point sits on a centre already, so any will do
+			centers.push(points[centers.len()]);
+			continue;
+		}
+		let mut target = pick(centers.len() as i32, total);
+		let chosen = weights.iter().position(|&weight| {
+			target -= weight;
+			target < 0f64 && weight > 0f64
+		}).unwrap_or_else(|| weights.iter().rposition(|&weight| weight > 0f64).unwrap_or(0));
+		centers.push(points[chosen]);
+	}
+
+	let mut assignments: Vec<usize> = points.iter().map(|&p| nearest_center(p, &centers)).collect();
+	for _ in 0..max_iters {
+		for (cluster, center) in centers.iter_mut().enumerate() {
+			let members: Vec<Vec2D<f64>> = points.iter().zip(assignments.iter()).filter(|&(_, &a)| a == cluster).map(|(&p, _)| p).collect();
+			// an emptied cluster keeps its old centre
+			if !members.is_empty() {
+				*center = centroid(&members);
+			}
+		}
+		let next: Vec<usize> = points.iter().map(|&p| nearest_center(p, &centers)).collect();
+		if next == assignments {
+			break;
+		}
+		assignments = next;
+	}
+	assignments
+}
+
+/// Clusters `points` by density with DBSCAN, returning each point's cluster id (numbered from zero in order of discovery),
+/// or `None` for noise. A point is a core point if at least `min_points` points (itself included) lie within `epsilon` of it;
+/// clusters are the groups of core points reachable from one another through such neighbourhoods, along with the non-core points
+/// on their fringes. Neighbourhoods are found with a `KdTree2D`, so clusters can take any shape and outliers are left out.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::cluster::dbscan;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(9f64, 9f64)];
+/// 	assert_eq!(dbscan(&points, 1.5, 2), vec![Some(0), Some(0), Some(0), None]);
+/// ```
+pub fn dbscan(points: &[Vec2D<f64>], epsilon: f64, min_points: usize) -> Vec<Option<usize>> {
+	let tree = KdTree2D::new(points);
+	let mut labels: Vec<Option<usize>> = vec![None; points.len()];
+	let mut visited = vec![false; points.len()];
+	let mut clusters = 0;
+	for start in 0..points.len() {
+		if visited[start] {
+			continue;
+		}
+		visited[start] = true;
+		let neighbours = tree.within_radius(points[start], epsilon);
+		if neighbours.len() < min_points {
+			// noise, unless a cluster reaches it later
+			continue;
+		}
+		let cluster = clusters;
+		clusters += 1;
+		labels[start] = Some(cluster);
+		let mut frontier = neighbours;
+		while let Some(point) = frontier.pop() {
+			if labels[point].is_none() {
+				labels[point] = Some(cluster);
+			}
+			if visited[point] {
+				continue;
+			}
+			visited[point] = true;
+			let reachable = tree.within_radius(points[point], epsilon);
+			if reachable.len() >= min_points {
+				frontier.extend(reachable);
+			}
+		}
+	}
+	labels
+}
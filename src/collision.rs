@@ -0,0 +1,307 @@
+use vec2d::Vec2D;
+use polygon;
+use polygon::{counter_clockwise, signed_area};
+
+/// copies a convex polygon into counter-clockwise order, starting from its lowest (then left-most) vertex
+fn normalised_convex(points: &[Vec2D<f64>]) -> Vec<Vec2D<f64>> {
+	let mut ordered = counter_clockwise(points);
+	let lowest = (0..ordered.len()).fold(0, |best, i| {
+		let (p, q) = (ordered[i], ordered[best]);
+		if p.y < q.y || (p.y == q.y && p.x < q.x) { i } else { best }
+	});
+	ordered.rotate_left(lowest);
+	ordered
+}
+
+/// Computes the Minkowski sum of two convex polygons (every `a + b` for points in each) by merging their edges in angular order.
+/// The result is a convex polygon with counter-clockwise vertices; parallel edges of the inputs are merged into one.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::collision::minkowski_sum;
+/// 	let triangle = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64)];
+/// 	let point = [Vec2D::new(5f64, 5f64)];
+/// 	assert_eq!(minkowski_sum(&triangle, &point), vec![Vec2D::new(5f64, 5f64), Vec2D::new(6f64, 5f64), Vec2D::new(5f64, 6f64)]);
+/// ```
+pub fn minkowski_sum(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> Vec<Vec2D<f64>> {
+	if a.is_empty() || b.is_empty() {
+		return Vec::new();
+	}
+	let (a, b) = (normalised_convex(a), normalised_convex(b));
+	let (n, m) = (a.len(), b.len());
+	let mut result = Vec::with_capacity(n + m);
+	let (mut i, mut j) = (0, 0);
+	while i < n || j < m {
+		result.push(a[i % n] + b[j % m]);
+		let cross = Vec2D::cross_product(a[(i + 1) % n] - a[i % n], b[(j + 1) % m] - b[j % m]);
+		let (advance_a, advance_b) = (cross >= 0f64 && i < n, cross <= 0f64 && j < m);
+		if advance_a {
+			i += 1;
+		}
+		if advance_b {
+			j += 1;
+		}
+		if !advance_a && !advance_b {
+			// only reachable through rounding error; keep walking whichever polygon still has edges
+			if i < n { i += 1; } else { j += 1; }
+		}
+	}
+	result
+}
+
+fn furthest_point(points: &[Vec2D<f64>], direction: Vec2D<f64>) -> Vec2D<f64> {
+	let mut best = points[0];
+	let mut best_distance = Vec2D::dot_product(best, direction);
+	for &point in &points[1..] {
+		let distance = Vec2D::dot_product(point, direction);
+		if distance > best_distance {
+			best = point;
+			best_distance = distance;
+		}
+	}
+	best
+}
+
+/// the support point of the Minkowski difference `a - b` in `direction`
+fn support(a: &[Vec2D<f64>], b: &[Vec2D<f64>], direction: Vec2D<f64>) -> Vec2D<f64> {
+	furthest_point(a, direction) - furthest_point(b, -direction)
+}
+
+/// `(a × b) × c`, i.e. the component of `c`'s direction perpendicular to `a` on the side given by `b`
+fn triple_product(a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>) -> Vec2D<f64> {
+	b * Vec2D::dot_product(a, c) - a * Vec2D::dot_product(b, c)
+}
+
+const GJK_MAX_ITERATIONS: usize = 64;
+
+/// runs GJK, returning the final simplex (a triangle enclosing the origin, or a degenerate one touching it) on intersection
+fn gjk(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> Option<Vec<Vec2D<f64>>> {
+	if a.is_empty() || b.is_empty() {
+		return None;
+	}
+	let mut direction = polygon::centroid(a) - polygon::centroid(b);
+	if direction == Vec2D::default() {
+		direction = Vec2D::new(1f64, 0f64);
+	}
+	let mut simplex = vec![support(a, b, direction)];
+	direction = -simplex[0];
+
+	for _ in 0..GJK_MAX_ITERATIONS {
+		if Vec2D::dot_product(direction, direction) < 1e-18 {
+			// the origin lies on the current simplex
+			return Some(simplex);
+		}
+		let point = support(a, b, direction);
+		if Vec2D::dot_product(point, direction) < 0f64 {
+			return None;
+		}
+		simplex.push(point);
+
+		let newest = simplex[simplex.len() - 1];
+		let to_origin = -newest;
+		if simplex.len() == 2 {
+			let edge = simplex[0] - newest;
+			direction = triple_product(edge, to_origin, edge);
+		} else {
+			let (edge_b, edge_c) = (simplex[1] - newest, simplex[0] - newest);
+			let perp_b = triple_product(edge_c, edge_b, edge_b);
+			let perp_c = triple_product(edge_b, edge_c, edge_c);
+			if Vec2D::dot_product(perp_b, to_origin) > 0f64 {
+				simplex.remove(0);
+				direction = perp_b;
+			} else if Vec2D::dot_product(perp_c, to_origin) > 0f64 {
+				simplex.remove(1);
+				direction = perp_c;
+			} else {
+				return Some(simplex);
+			}
+		}
+	}
+	None
+}
+
+/// Tests whether two convex shapes (given by their vertices, in any order) intersect, using the Gilbert-Johnson-Keerthi algorithm.
+/// Shapes that merely touch count as intersecting.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::collision::gjk_intersects;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let triangle = [Vec2D::new(1f64, 1f64), Vec2D::new(5f64, 1f64), Vec2D::new(3f64, 4f64)];
+/// 	assert!(gjk_intersects(&square, &triangle));
+/// ```
+pub fn gjk_intersects(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> bool {
+	gjk(a, b).is_some()
+}
+
+const EPA_TOLERANCE: f64 = 1e-9;
+const EPA_MAX_ITERATIONS: usize = 128;
+
+/// Finds the minimum translation vector separating two intersecting convex shapes, using the Expanding Polytope Algorithm seeded by GJK.
+/// Translating `a` by the returned vector (or `b` by its negation) leaves the shapes just touching.
+/// Returns `None` if the shapes do not intersect, and the zero vector if they only touch.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::collision::epa_penetration;
+/// 	let a = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let b = [Vec2D::new(1.5, 0f64), Vec2D::new(3.5, 0f64), Vec2D::new(3.5, 2f64), Vec2D::new(1.5, 2f64)];
+/// 	let mtv = epa_penetration(&a, &b).unwrap();
+/// 	assert!((mtv - Vec2D::new(-0.5, 0f64)).magnitude() < 1e-9);
+/// ```
+pub fn epa_penetration(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> Option<Vec2D<f64>> {
+	let mut polytope = gjk(a, b)?;
+	polytope.dedup();
+
+	if polytope.len() == 2 || (polytope.len() == 3 && signed_area(&polytope) == 0f64) {
+		// the origin lies on a segment through the difference; widen it into a triangle if the difference has any area
+		let (p, q) = (polytope[0], polytope[polytope.len() - 1]);
+		let normal = (q - p).perp();
+		let widened = [normal, -normal].iter().map(|&n| support(a, b, n)).find(|&r| polygon::orientation(p, q, r).abs() > EPA_TOLERANCE);
+		match widened {
+			Some(r) => polytope = vec![p, q, r],
+			None => return Some(Vec2D::default())
+		}
+	}
+	if polytope.len() < 3 {
+		// the origin is itself an extreme point of the difference, so the shapes only touch
+		return Some(Vec2D::default());
+	}
+	if signed_area(&polytope) < 0f64 {
+		polytope.reverse();
+	}
+
+	let mut best = Vec2D::default();
+	for _ in 0..EPA_MAX_ITERATIONS {
+		let mut closest = (f64::INFINITY, 0, Vec2D::default());
+		for i in 0..polytope.len() {
+			let edge = polytope[(i + 1) % polytope.len()] - polytope[i];
+			if edge == Vec2D::default() {
+				continue;
+			}
+			let normal = (-edge.perp()).to_unit();
+			let distance = Vec2D::dot_product(normal, polytope[i]);
+			if distance < closest.0 {
+				closest = (distance, i, normal);
+			}
+		}
+		let (distance, index, normal) = closest;
+		best = normal * distance;
+		let point = support(a, b, normal);
+		if Vec2D::dot_product(point, normal) - distance < EPA_TOLERANCE {
+			break;
+		}
+		polytope.insert(index + 1, point);
+	}
+	Some(-best)
+}
+
+/// Layer/mask collision filtering: each object sits on the layers set in its `layer` bits and collides with the layers set in its `mask`.
+/// Two objects collide only if each one's mask accepts the other's layer, so either side can opt out.
+/// # Examples
+/// ```
+/// 	use phys2d::collision::should_collide;
+/// 	const PLAYER: u32 = 1;
+/// 	const ENEMY: u32 = 2;
+/// 	assert!(should_collide(PLAYER, ENEMY, ENEMY, PLAYER | ENEMY));
+/// 	assert!(!should_collide(ENEMY, PLAYER, ENEMY, PLAYER));
+/// ```
+pub fn should_collide(layer_a: u32, mask_a: u32, layer_b: u32, mask_b: u32) -> bool {
+	(mask_a & layer_b) != 0 && (mask_b & layer_a) != 0
+}
+
+/// where two shapes touch: the contact `normal` (a unit vector pointing from the first shape towards the second),
+/// how deeply they overlap along it, and the contact points
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Manifold {
+	pub normal: Vec2D<f64>,
+	pub depth: f64,
+	pub points: Vec<Vec2D<f64>>,
+	/// the indices of the first and second shapes' bodies, as used by `rigid_body::solve_contacts`
+	/// (`contact_manifold` leaves these as 0 and 1; see `with_bodies`)
+	pub body_a: usize,
+	pub body_b: usize
+}
+
+impl Manifold {
+	/// Returns the manifold with its contact attributed to the bodies at `body_a` and `body_b`.
+	pub fn with_bodies(self, body_a: usize, body_b: usize) -> Manifold {
+		Manifold{body_a, body_b, ..self}
+	}
+}
+
+/// the outward unit normal of the counter-clockwise edge from `a` to `b`
+fn outward_normal(a: Vec2D<f64>, b: Vec2D<f64>) -> Vec2D<f64> {
+	-(b - a).perp().to_unit()
+}
+
+/// the edge of `a` (counter-clockwise) along whose normal `b` is furthest out, with that separation
+/// (negative while the polygons overlap along every edge normal)
+fn max_separation(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> (usize, f64) {
+	(0..a.len()).map(|i| {
+		let (start, normal) = (a[i], outward_normal(a[i], a[(i + 1) % a.len()]));
+		let separation = b.iter().map(|&p| Vec2D::dot_product(normal, p - start)).fold(f64::INFINITY, f64::min);
+		(i, separation)
+	}).fold((0, f64::NEG_INFINITY), |best, candidate| if candidate.1 > best.1 { candidate } else { best })
+}
+
+/// keeps the part of the segment (or single point) `points` on the side of the plane `dot(normal, p) <= offset`;
+/// a segment with only an endpoint on the plane clips down to that one point
+fn clip_segment(points: &[Vec2D<f64>], normal: Vec2D<f64>, offset: f64) -> Vec<Vec2D<f64>> {
+	let distances: Vec<f64> = points.iter().map(|&p| Vec2D::dot_product(normal, p) - offset).collect();
+	let mut clipped: Vec<Vec2D<f64>> = (0..points.len()).filter(|&i| distances[i] <= 0f64).map(|i| points[i]).collect();
+	if points.len() == 2 && distances[0] * distances[1] < 0f64 {
+		let t = distances[0] / (distances[0] - distances[1]);
+		clipped.push(points[0] + (points[1] - points[0]) * t);
+	}
+	clipped
+}
+
+/// Computes the contact manifold of two overlapping convex polygons (in either winding order) using the separating axis theorem,
+/// finding the axis of least penetration and then clipping the most opposed (incident) edge of one polygon against the sides of
+/// the reference edge of the other. The contact points lie on the incident polygon within the reference one, with `depth` the deepest of them.
+/// Returns `None` if the polygons are separated; touching polygons give a zero-depth manifold.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::collision::contact_manifold;
+/// 	let floor = [Vec2D::new(-5f64, -1f64), Vec2D::new(5f64, -1f64), Vec2D::new(5f64, 0f64), Vec2D::new(-5f64, 0f64)];
+/// 	let crate_box = [Vec2D::new(0f64, -0.25), Vec2D::new(1f64, -0.25), Vec2D::new(1f64, 0.75), Vec2D::new(0f64, 0.75)];
+/// 	let manifold = contact_manifold(&floor, &crate_box).unwrap();
+/// 	assert_eq!(manifold.normal, Vec2D::new(0f64, 1f64));
+/// 	assert_eq!(manifold.depth, 0.25);
+/// 	assert_eq!(manifold.points.len(), 2);
+/// ```
+pub fn contact_manifold(a: &[Vec2D<f64>], b: &[Vec2D<f64>]) -> Option<Manifold> {
+	if a.len() < 2 || b.len() < 2 {
+		return None;
+	}
+	let (a, b) = (normalised_convex(a), normalised_convex(b));
+	let (edge_a, separation_a) = max_separation(&a, &b);
+	let (edge_b, separation_b) = max_separation(&b, &a);
+	if separation_a > 0f64 || separation_b > 0f64 {
+		return None;
+	}
+
+	// prefer `a` as the reference so nearly equal axes don't make the result flicker between the two
+	let flip = separation_b > separation_a + 1e-9 * separation_a.abs().max(1f64);
+	let (reference, incident, edge) = if flip { (&b, &a, edge_b) } else { (&a, &b, edge_a) };
+	let (v1, v2) = (reference[edge], reference[(edge + 1) % reference.len()]);
+	let normal = outward_normal(v1, v2);
+
+	let incident_edge = (0..incident.len()).map(|i| {
+		(i, Vec2D::dot_product(normal, outward_normal(incident[i], incident[(i + 1) % incident.len()])))
+	}).fold((0, f64::INFINITY), |best, candidate| if candidate.1 < best.1 { candidate } else { best }).0;
+	let segment = [incident[incident_edge], incident[(incident_edge + 1) % incident.len()]];
+
+	// clip to the slab between the reference edge's side planes, then keep what is behind the reference face
+	let tangent = (v2 - v1).to_unit();
+	let clipped = clip_segment(&segment, -tangent, -Vec2D::dot_product(tangent, v1));
+	let clipped = clip_segment(&clipped, tangent, Vec2D::dot_product(tangent, v2));
+	let points: Vec<Vec2D<f64>> = clipped.into_iter().filter(|&p| Vec2D::dot_product(normal, p - v1) <= 0f64).collect();
+	if points.is_empty() {
+		return None;
+	}
+	let depth = points.iter().map(|&p| -Vec2D::dot_product(normal, p - v1)).fold(0f64, f64::max);
+	Some(Manifold{normal: if flip { -normal } else { normal }, depth, points, body_a: 0, body_b: 1})
+}
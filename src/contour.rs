@@ -0,0 +1,69 @@
+use vec2d::Vec2D;
+use shapes::{AABB, Segment2D};
+
+/// Extracts the `iso`-contour of the scalar field `sample` over `bounds` as line segments using marching squares,
+/// sampling on a grid of `resolution.x` by `resolution.y` cells. Crossing points are placed by linear interpolation along cell edges,
+/// and ambiguous (saddle) cells are resolved by sampling the cell's centre.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::contour::marching_squares;
+/// 	let bounds = AABB::new(Vec2D::new(-1f64, -1f64), Vec2D::new(1f64, 1f64));
+/// 	let segments = marching_squares(|p| p.x, bounds, Vec2D::new(4, 4), 0.25);
+/// 	assert_eq!(segments.len(), 4);
+/// 	assert!(segments.iter().all(|s| s.start.x == 0.25 && s.end.x == 0.25));
+/// ```
+pub fn marching_squares(sample: impl Fn(Vec2D<f64>) -> f64, bounds: AABB<f64>, resolution: Vec2D<usize>, iso: f64) -> Vec<Segment2D<f64>> {
+	let mut segments = Vec::new();
+	if resolution.x == 0 || resolution.y == 0 {
+		return segments;
+	}
+	let cell_size = Vec2D::new((bounds.max.x - bounds.min.x) / resolution.x as f64, (bounds.max.y - bounds.min.y) / resolution.y as f64);
+	let point_at = |i: usize, j: usize| bounds.min + Vec2D::new(cell_size.x * i as f64, cell_size.y * j as f64);
+
+	let columns = resolution.x + 1;
+	let mut values = Vec::with_capacity(columns * (resolution.y + 1));
+	for j in 0..resolution.y + 1 {
+		for i in 0..columns {
+			values.push(sample(point_at(i, j)));
+		}
+	}
+
+	for j in 0..resolution.y {
+		for i in 0..resolution.x {
+			// corners counter-clockwise from the bottom left, and the edges leaving each of them
+			let corners = [point_at(i, j), point_at(i + 1, j), point_at(i + 1, j + 1), point_at(i, j + 1)];
+			let corner_values = [values[j * columns + i], values[j * columns + i + 1], values[(j + 1) * columns + i + 1], values[(j + 1) * columns + i]];
+			let above: Vec<bool> = corner_values.iter().map(|&v| v >= iso).collect();
+
+			let mut crossings = [None; 4];
+			for edge in 0..4 {
+				let next = (edge + 1) % 4;
+				if above[edge] != above[next] {
+					let t = (iso - corner_values[edge]) / (corner_values[next] - corner_values[edge]);
+					crossings[edge] = Some(corners[edge] + (corners[next] - corners[edge]) * t);
+				}
+			}
+
+			let crossed: Vec<Vec2D<f64>> = crossings.iter().filter_map(|&c| c).collect();
+			match crossed.len() {
+				2 => segments.push(Segment2D::new(crossed[0], crossed[1])),
+				4 => {
+					let centre_above = sample((corners[0] + corners[2]) / 2f64) >= iso;
+					let edge = |k: usize| crossings[k].unwrap();
+					if centre_above == above[0] {
+						// the bottom left and top right corners join through the centre, cutting off the other two
+						segments.push(Segment2D::new(edge(0), edge(1)));
+						segments.push(Segment2D::new(edge(2), edge(3)));
+					} else {
+						segments.push(Segment2D::new(edge(3), edge(0)));
+						segments.push(Segment2D::new(edge(1), edge(2)));
+					}
+				},
+				_ => {}
+			}
+		}
+	}
+	segments
+}
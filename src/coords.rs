@@ -0,0 +1,50 @@
+use vec2d::Vec2D;
+
+/// Projects a cartesian (map) position onto the screen using the standard 2:1 isometric projection,
+/// where each map axis runs diagonally down the screen, two units across for every unit down.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::coords::cart_to_iso;
+/// 	assert_eq!(cart_to_iso(Vec2D::new(1f64, 0f64)), Vec2D::new(1f64, 0.5));
+/// 	assert_eq!(cart_to_iso(Vec2D::new(0f64, 1f64)), Vec2D::new(-1f64, 0.5));
+/// ```
+pub fn cart_to_iso(v: Vec2D<f64>) -> Vec2D<f64> {
+	Vec2D::new(v.x - v.y, (v.x + v.y) / 2f64)
+}
+
+/// Converts an isometric screen position back to cartesian (map) coordinates; the inverse of `cart_to_iso`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::coords::iso_to_cart;
+/// 	assert_eq!(iso_to_cart(Vec2D::new(0f64, 1f64)), Vec2D::new(1f64, 1f64));
+/// ```
+pub fn iso_to_cart(v: Vec2D<f64>) -> Vec2D<f64> {
+	Vec2D::new(v.y + v.x / 2f64, v.y - v.x / 2f64)
+}
+
+/// Converts a pixel position (origin at the top-left, y down) on a screen of `screen_size` pixels into
+/// normalized device coordinates (origin at the centre, y up, spanning `[-1, 1]` on both axes across the screen).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::coords::screen_to_ndc;
+/// 	let screen = Vec2D::new(800f64, 600f64);
+/// 	assert_eq!(screen_to_ndc(Vec2D::new(0f64, 0f64), screen), Vec2D::new(-1f64, 1f64));
+/// 	assert_eq!(screen_to_ndc(Vec2D::new(600f64, 450f64), screen), Vec2D::new(0.5, -0.5));
+/// ```
+pub fn screen_to_ndc(pixel: Vec2D<f64>, screen_size: Vec2D<f64>) -> Vec2D<f64> {
+	Vec2D::new(pixel.x / screen_size.x * 2f64 - 1f64, 1f64 - pixel.y / screen_size.y * 2f64)
+}
+
+/// Converts normalized device coordinates back into a pixel position on a screen of `screen_size` pixels; the inverse of `screen_to_ndc`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::coords::ndc_to_screen;
+/// 	assert_eq!(ndc_to_screen(Vec2D::new(1f64, -1f64), Vec2D::new(800f64, 600f64)), Vec2D::new(800f64, 600f64));
+/// ```
+pub fn ndc_to_screen(ndc: Vec2D<f64>, screen_size: Vec2D<f64>) -> Vec2D<f64> {
+	Vec2D::new((ndc.x + 1f64) / 2f64 * screen_size.x, (1f64 - ndc.y) / 2f64 * screen_size.y)
+}
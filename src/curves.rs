@@ -0,0 +1,67 @@
+use vec2d::Vec2D;
+
+/// Evaluates the cubic Bézier curve with control points `p0` to `p3` at the parameter `t` (from `p0` at 0 to `p3` at 1).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::curves::cubic_bezier;
+/// 	let (p0, p1, p2, p3) = (Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 2f64), Vec2D::new(2f64, 2f64), Vec2D::new(2f64, 0f64));
+/// 	assert_eq!(cubic_bezier(p0, p1, p2, p3, 0f64), p0);
+/// 	assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.5), Vec2D::new(1f64, 1.5));
+/// ```
+pub fn cubic_bezier(p0: Vec2D<f64>, p1: Vec2D<f64>, p2: Vec2D<f64>, p3: Vec2D<f64>, t: f64) -> Vec2D<f64> {
+	let u = 1f64 - t;
+	p0 * (u * u * u) + p1 * (3f64 * u * u * t) + p2 * (3f64 * u * t * t) + p3 * (t * t * t)
+}
+
+/// Builds a lookup table from the parameter `t` to the distance travelled along the cubic Bézier curve `p0`..`p3`,
+/// approximating the curve by `samples` (at least one) straight chords at evenly spaced `t`.
+/// The table has `samples + 1` `(t, length)` entries, from `(0, 0)` to `(1, total length)`, for use with `bezier_point_at_distance`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::curves::bezier_arc_length_lut;
+/// 	// a straight line with evenly spaced control points
+/// 	let lut = bezier_arc_length_lut(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(3f64, 0f64), 4);
+/// 	assert_eq!(lut.len(), 5);
+/// 	assert_eq!(lut[4], (1f64, 3f64));
+/// ```
+pub fn bezier_arc_length_lut(p0: Vec2D<f64>, p1: Vec2D<f64>, p2: Vec2D<f64>, p3: Vec2D<f64>, samples: usize) -> Vec<(f64, f64)> {
+	let samples = samples.max(1);
+	let mut lut = Vec::with_capacity(samples + 1);
+	let (mut previous, mut length) = (p0, 0f64);
+	lut.push((0f64, 0f64));
+	for i in 1..=samples {
+		let t = i as f64 / samples as f64;
+		let point = cubic_bezier(p0, p1, p2, p3, t);
+		length += (point - previous).magnitude();
+		lut.push((t, length));
+		previous = point;
+	}
+	lut
+}
+
+/// Finds the point `distance` along the cubic Bézier curve `p0`..`p3` (clamped to the curve's ends), using a `lut` built by
+/// `bezier_arc_length_lut` to convert the distance into the curve parameter. Stepping the distance evenly moves along the curve at
+/// a constant speed, unlike stepping `t`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::curves::{bezier_arc_length_lut, bezier_point_at_distance};
+/// 	// control points bunched at the start make `t` move slowly there
+/// 	let (p0, p1, p2, p3) = (Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64), Vec2D::new(8f64, 0f64));
+/// 	let lut = bezier_arc_length_lut(p0, p1, p2, p3, 256);
+/// 	let halfway = bezier_point_at_distance(p0, p1, p2, p3, &lut, 4f64);
+/// 	assert!((halfway - Vec2D::new(4f64, 0f64)).magnitude() < 1e-3);
+/// ```
+pub fn bezier_point_at_distance(p0: Vec2D<f64>, p1: Vec2D<f64>, p2: Vec2D<f64>, p3: Vec2D<f64>, lut: &[(f64, f64)], distance: f64) -> Vec2D<f64> {
+	if lut.len() < 2 {
+		return p0;
+	}
+	let distance = distance.clamp(0f64, lut[lut.len() - 1].1);
+	let after = lut.partition_point(|&(_, length)| length < distance).clamp(1, lut.len() - 1);
+	let ((t0, length0), (t1, length1)) = (lut[after - 1], lut[after]);
+	let span = length1 - length0;
+	let t = if span == 0f64 { t0 } else { t0 + (t1 - t0) * (distance - length0) / span };
+	cubic_bezier(p0, p1, p2, p3, t)
+}
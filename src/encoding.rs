@@ -0,0 +1,161 @@
+use vec2d::Vec2D;
+
+/// maps signed integers onto unsigned ones so that values near zero stay small: 0, -1, 1, -2, ... become 0, 1, 2, 3, ...
+fn zigzag(value: i32) -> u32 {
+	((value << 1) ^ (value >> 31)) as u32
+}
+
+fn unzigzag(value: u32) -> i32 {
+	((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+/// the SplitMix64 finalizer, scrambling every input bit across the whole output
+fn splitmix64(value: u64) -> u64 {
+	let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+	z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+	z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+	z ^ (z >> 31)
+}
+
+impl Vec2D<i32> {
+	/// Packs the cell into a single `u64`, zigzag-encoding each coordinate (x in the high 32 bits, y in the low) so any `i32` pair round trips.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(0, 0).to_cell_id(), 0);
+	/// 	assert_eq!(Vec2D::new(1, -1).to_cell_id(), (2 << 32) | 1);
+	/// ```
+	pub fn to_cell_id(&self) -> u64 {
+		(u64::from(zigzag(self.x)) << 32) | u64::from(zigzag(self.y))
+	}
+
+	/// Unpacks a cell packed by `to_cell_id`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let cell = Vec2D::new(-12, 345);
+	/// 	assert_eq!(Vec2D::from_cell_id(cell.to_cell_id()), cell);
+	/// ```
+	pub fn from_cell_id(id: u64) -> Vec2D<i32> {
+		Vec2D::new(unzigzag((id >> 32) as u32), unzigzag(id as u32))
+	}
+
+	/// Hashes the cell together with a `seed` into a well-mixed `u64` (using SplitMix64), for reproducible procedural generation.
+	/// Unlike `std::hash::Hash` with the standard library's hashers, the result is fixed: the same on every run and platform.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let cell = Vec2D::new(3, -7);
+	/// 	assert_eq!(cell.seeded_hash(42), cell.seeded_hash(42));
+	/// 	assert_ne!(cell.seeded_hash(42), cell.seeded_hash(43));
+	/// 	assert_ne!(cell.seeded_hash(42), Vec2D::new(-7, 3).seeded_hash(42));
+	/// ```
+	pub fn seeded_hash(&self, seed: u64) -> u64 {
+		splitmix64(splitmix64(seed) ^ self.to_cell_id())
+	}
+}
+
+/// spreads the bits of `value` out to the even bit positions of a `u64`
+fn spread_bits(value: u32) -> u64 {
+	let mut v = u64::from(value);
+	v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+	v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+	v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+	v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+	(v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// gathers the even bits of `value` back together; the inverse of `spread_bits`
+fn compact_bits(value: u64) -> u32 {
+	let mut v = value & 0x5555_5555_5555_5555;
+	v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+	v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+	v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+	v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+	((v | (v >> 16)) & 0x0000_0000_FFFF_FFFF) as u32
+}
+
+impl Vec2D<u32> {
+	/// Interleaves the bits of the coordinates into a Morton (Z-order) code, x in the even bits and y in the odd ones.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1u32, 0u32).morton_encode(), 1);
+	/// 	assert_eq!(Vec2D::new(0u32, 1u32).morton_encode(), 2);
+	/// 	assert_eq!(Vec2D::new(3u32, 3u32).morton_encode(), 15);
+	/// ```
+	pub fn morton_encode(&self) -> u64 {
+		spread_bits(self.x) | (spread_bits(self.y) << 1)
+	}
+
+	/// Recovers the coordinates from a Morton code produced by `morton_encode`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::morton_decode(6), Vec2D::new(2u32, 1u32));
+	/// ```
+	pub fn morton_decode(code: u64) -> Vec2D<u32> {
+		Vec2D::new(compact_bits(code), compact_bits(code >> 1))
+	}
+}
+
+/// rotates/flips a quadrant of side `n` so that each sub-curve of the Hilbert curve is oriented correctly
+fn hilbert_rotate(n: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+	if ry == 0 {
+		if rx == 1 {
+			*x = n - 1 - *x;
+			*y = n - 1 - *y;
+		}
+		std::mem::swap(x, y);
+	}
+}
+
+impl Vec2D<u32> {
+	/// Finds the cell's index along the Hilbert curve filling a `2^order` by `2^order` grid (starting at the origin and ending at `(2^order - 1, 0)`).
+	/// Unlike Morton codes, consecutive indices are always adjacent cells. Panics if `order` exceeds 32 or the cell lies outside the grid.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let curve: Vec<u64> = [(0u32, 0u32), (0, 1), (1, 1), (1, 0)].iter().map(|&(x, y)| Vec2D::new(x, y).hilbert_encode(1)).collect();
+	/// 	assert_eq!(curve, vec![0, 1, 2, 3]);
+	/// ```
+	pub fn hilbert_encode(&self, order: u32) -> u64 {
+		assert!(order <= 32, "hilbert_encode supports orders up to 32");
+		let n = 1u64 << order;
+		let (mut x, mut y) = (u64::from(self.x), u64::from(self.y));
+		assert!(x < n && y < n, "cell lies outside the 2^order grid");
+		let mut index = 0;
+		let mut s = n / 2;
+		while s > 0 {
+			let rx = u64::from(x & s > 0);
+			let ry = u64::from(y & s > 0);
+			index += s * s * ((3 * rx) ^ ry);
+			hilbert_rotate(n, &mut x, &mut y, rx, ry);
+			s /= 2;
+		}
+		index
+	}
+
+	/// Recovers the cell at position `index` along the Hilbert curve of the given `order`; the inverse of `hilbert_encode`.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::hilbert_decode(Vec2D::new(5u32, 2u32).hilbert_encode(3), 3), Vec2D::new(5u32, 2u32));
+	/// ```
+	pub fn hilbert_decode(index: u64, order: u32) -> Vec2D<u32> {
+		assert!(order <= 32, "hilbert_decode supports orders up to 32");
+		let n = 1u64 << order;
+		let (mut x, mut y, mut t) = (0u64, 0u64, index);
+		let mut s = 1;
+		while s < n {
+			let rx = 1 & (t / 2);
+			let ry = 1 & (t ^ rx);
+			hilbert_rotate(s, &mut x, &mut y, rx, ry);
+			x += s * rx;
+			y += s * ry;
+			t /= 4;
+			s *= 2;
+		}
+		Vec2D::new(x as u32, y as u32)
+	}
+}
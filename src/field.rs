@@ -0,0 +1,97 @@
+use vec2d::Vec2D;
+use integrate::rk4_step;
+
+/// within this distance of a field's centre its strength stops growing, so the field stays finite there
+const MIN_FIELD_DISTANCE: f64 = 1e-3;
+
+/// Samples a radial gravity field at `point`: an acceleration pulling towards `center` with magnitude
+/// `strength / distance^falloff` (so a `falloff` of 2 is Newtonian gravity and 0 is a constant pull).
+/// Closer than a small minimum distance the magnitude is held at its value there, and at the centre itself the field is zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::radial_gravity;
+/// 	let pull = radial_gravity(Vec2D::new(0f64, 2f64), Vec2D::new(0f64, 0f64), 8f64, 2f64);
+/// 	assert_eq!(pull, Vec2D::new(0f64, -2f64));
+/// ```
+pub fn radial_gravity(point: Vec2D<f64>, center: Vec2D<f64>, strength: f64, falloff: f64) -> Vec2D<f64> {
+	let offset = center - point;
+	let distance = offset.magnitude();
+	if distance == 0f64 {
+		return Vec2D::default();
+	}
+	offset * (strength / distance.max(MIN_FIELD_DISTANCE).powf(falloff) / distance)
+}
+
+/// Samples a swirling vortex field at `point`: a velocity perpendicular to the radius from `center`, circling counter-clockwise
+/// for a positive `strength` (clockwise for a negative one). The field turns like a rigid disc at `strength` radians per unit time,
+/// so the speed grows with distance from the centre.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::vortex_field;
+/// 	assert_eq!(vortex_field(Vec2D::new(2f64, 0f64), Vec2D::new(0f64, 0f64), 0.5), Vec2D::new(0f64, 1f64));
+/// ```
+pub fn vortex_field(point: Vec2D<f64>, center: Vec2D<f64>, strength: f64) -> Vec2D<f64> {
+	(point - center).perp() * strength
+}
+
+/// Estimates the gradient of the scalar `field` at `p` by central finite differences `epsilon` either side on each axis.
+/// For a signed distance field this is the outward surface normal (of roughly unit length).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::gradient;
+/// 	let slope = gradient(|p| 3f64 * p.x - p.y, Vec2D::new(5f64, 5f64), 1e-3);
+/// 	assert!((slope - Vec2D::new(3f64, -1f64)).magnitude() < 1e-9);
+/// ```
+pub fn gradient(field: impl Fn(Vec2D<f64>) -> f64, p: Vec2D<f64>, epsilon: f64) -> Vec2D<f64> {
+	let (dx, dy) = (Vec2D::new(epsilon, 0f64), Vec2D::new(0f64, epsilon));
+	Vec2D::new(field(p + dx) - field(p - dx), field(p + dy) - field(p - dy)) / (2f64 * epsilon)
+}
+
+/// Estimates the divergence `∂Fx/∂x + ∂Fy/∂y` of the vector `field` at `p` by central finite differences `epsilon` either side:
+/// how much the field flows outwards from around `p` (positive at sources, negative at sinks).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::divergence;
+/// 	assert!((divergence(|p| p * 2f64, Vec2D::new(1f64, -3f64), 1e-3) - 4f64).abs() < 1e-9);
+/// ```
+pub fn divergence(field: impl Fn(Vec2D<f64>) -> Vec2D<f64>, p: Vec2D<f64>, epsilon: f64) -> f64 {
+	let (dx, dy) = (Vec2D::new(epsilon, 0f64), Vec2D::new(0f64, epsilon));
+	(field(p + dx).x - field(p - dx).x + field(p + dy).y - field(p - dy).y) / (2f64 * epsilon)
+}
+
+/// Estimates the (scalar, 2D) curl `∂Fy/∂x - ∂Fx/∂y` of the vector `field` at `p` by central finite differences `epsilon` either side:
+/// how much the field swirls around `p`, positive for counter-clockwise rotation (twice its angular velocity).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::curl;
+/// 	assert!((curl(|p| p.perp(), Vec2D::new(1f64, -3f64), 1e-3) - 2f64).abs() < 1e-9);
+/// ```
+pub fn curl(field: impl Fn(Vec2D<f64>) -> Vec2D<f64>, p: Vec2D<f64>, epsilon: f64) -> f64 {
+	let (dx, dy) = (Vec2D::new(epsilon, 0f64), Vec2D::new(0f64, epsilon));
+	(field(p + dx).y - field(p - dx).y - (field(p + dy).x - field(p - dy).x)) / (2f64 * epsilon)
+}
+
+/// Traces the path a massless particle dropped at `start` would follow through the velocity `field`, taking `steps`
+/// fourth-order Runge-Kutta steps of `step` time units each. Returns the `steps + 1` positions along it, starting with `start`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::field::trace_streamline;
+/// 	let path = trace_streamline(|_| Vec2D::new(2f64, 0f64), Vec2D::new(0f64, 1f64), 0.5, 2);
+/// 	assert_eq!(path, vec![Vec2D::new(0f64, 1f64), Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 1f64)]);
+/// ```
+pub fn trace_streamline(field: impl Fn(Vec2D<f64>) -> Vec2D<f64>, start: Vec2D<f64>, step: f64, steps: usize) -> Vec<Vec2D<f64>> {
+	let mut path = Vec::with_capacity(steps + 1);
+	let mut position = start;
+	path.push(position);
+	for _ in 0..steps {
+		position = rk4_step(position, &field, step);
+		path.push(position);
+	}
+	path
+}
@@ -0,0 +1,56 @@
+use vec2d::Vec2D;
+
+/// smoothing factor of an exponential low-pass filter with the given cutoff frequency (in Hz) over a timestep of `dt`
+fn smoothing_factor(cutoff: f64, dt: f64) -> f64 {
+	let tau = 1f64 / (2f64 * std::f64::consts::PI * cutoff);
+	1f64 / (1f64 + tau / dt)
+}
+
+/// a 1-euro filter: an adaptive low-pass filter that smooths heavily while the input is slow-moving (removing jitter)
+/// and raises its cutoff as the input speeds up (reducing lag).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OneEuroFilter2D {
+	/// the cutoff frequency (in Hz) used while the input is stationary; lower values remove more jitter
+	pub min_cutoff: f64,
+	/// how much the cutoff rises with the speed of the input; higher values reduce lag
+	pub beta: f64,
+	/// the cutoff frequency (in Hz) used to smooth the estimated speed
+	pub derivative_cutoff: f64,
+	previous: Option<Vec2D<f64>>,
+	derivative: Vec2D<f64>
+}
+
+impl OneEuroFilter2D {
+	/// Creates a new 1-euro filter with no history.
+	pub fn new(min_cutoff: f64, beta: f64, derivative_cutoff: f64) -> OneEuroFilter2D {
+		OneEuroFilter2D{min_cutoff, beta, derivative_cutoff, previous: None, derivative: Vec2D::default()}
+	}
+
+	/// Feeds a new sample taken `dt` seconds after the previous one and returns the filtered value.
+	/// The first sample is passed through unchanged.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::filter::OneEuroFilter2D;
+	/// 	let mut filter = OneEuroFilter2D::new(1f64, 0f64, 1f64);
+	/// 	assert_eq!(filter.filter(Vec2D::new(0f64, 0f64), 0.1), Vec2D::new(0f64, 0f64));
+	/// 	let smoothed = filter.filter(Vec2D::new(10f64, 0f64), 0.1);
+	/// 	assert!(smoothed.x > 0f64 && smoothed.x < 10f64);
+	/// ```
+	pub fn filter(&mut self, value: Vec2D<f64>, dt: f64) -> Vec2D<f64> {
+		let previous = match self.previous {
+			Some(previous) if dt > 0f64 => previous,
+			Some(previous) => return previous,
+			None => {
+				self.previous = Some(value);
+				return value;
+			}
+		};
+		let raw_derivative = (value - previous) / dt;
+		self.derivative += (raw_derivative - self.derivative) * smoothing_factor(self.derivative_cutoff, dt);
+		let cutoff = self.min_cutoff + self.beta * self.derivative.magnitude();
+		let filtered = previous + (value - previous) * smoothing_factor(cutoff, dt);
+		self.previous = Some(filtered);
+		filtered
+	}
+}
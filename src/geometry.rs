@@ -0,0 +1,546 @@
+use std::collections::{HashMap, HashSet};
+use vec2d::Vec2D;
+use shapes::Circle;
+use noise::lattice_hash;
+use polygon::signed_area;
+
+/// relative slack allowed when testing whether a point lies on a computed circle, to absorb rounding error
+const CIRCLE_TOLERANCE: f64 = 1e-9;
+
+fn encloses(circle: &Circle<f64>, point: Vec2D<f64>) -> bool {
+	(point - circle.center).magnitude() <= circle.radius + CIRCLE_TOLERANCE * circle.radius.max(1f64)
+}
+
+/// the smallest circle with `a` and `b` on its boundary
+fn diametral_circle(a: Vec2D<f64>, b: Vec2D<f64>) -> Circle<f64> {
+	Circle::new((a + b) / 2f64, (b - a).magnitude() / 2f64)
+}
+
+/// Finds the circumcircle of the triangle `abc`: the circle passing through all three points, or `None` if they are collinear.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::circumcircle;
+/// 	let circle = circumcircle(Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(0f64, 2f64)).unwrap();
+/// 	assert_eq!(circle.center, Vec2D::new(2f64, 1f64));
+/// 	assert!(circumcircle(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 3f64)).is_none());
+/// ```
+pub fn circumcircle(a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>) -> Option<Circle<f64>> {
+	let (ab, ac) = (b - a, c - a);
+	let d = 2f64 * Vec2D::cross_product(ab, ac);
+	if d == 0f64 {
+		return None;
+	}
+	let (ab_sq, ac_sq) = (Vec2D::dot_product(ab, ab), Vec2D::dot_product(ac, ac));
+	let offset = Vec2D::new(ac.y * ab_sq - ab.y * ac_sq, ab.x * ac_sq - ac.x * ab_sq) / d;
+	Some(Circle::new(a + offset, offset.magnitude()))
+}
+
+/// Tests whether `d` lies strictly inside the circumcircle of the triangle `abc` (in either winding order), using the standard determinant test.
+/// Collinear `abc` have no circumcircle, so nothing lies inside.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::in_circle;
+/// 	let (a, b, c) = (Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64), Vec2D::new(-1f64, 0f64));
+/// 	assert!(in_circle(a, b, c, Vec2D::new(0.5, -0.5)));
+/// 	assert!(!in_circle(a, b, c, Vec2D::new(0f64, -1f64)));
+/// ```
+pub fn in_circle(a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>, d: Vec2D<f64>) -> bool {
+	let (ad, bd, cd) = (a - d, b - d, c - d);
+	let determinant = Vec2D::dot_product(ad, ad) * Vec2D::cross_product(bd, cd)
+		- Vec2D::dot_product(bd, bd) * Vec2D::cross_product(ad, cd)
+		+ Vec2D::dot_product(cd, cd) * Vec2D::cross_product(ad, bd);
+	determinant * Vec2D::cross_product(b - a, c - a) > 0f64
+}
+
+/// the smallest circle with all three points on or within it, used when `a`, `b` and `c` must all touch the boundary
+fn circle_from_three(a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>) -> Circle<f64> {
+	circumcircle(a, b, c).unwrap_or_else(|| {
+		// collinear: the two furthest-apart points span the others
+		let candidates = [diametral_circle(a, b), diametral_circle(a, c), diametral_circle(b, c)];
+		*candidates.iter().max_by(|x, y| x.radius.total_cmp(&y.radius)).unwrap()
+	})
+}
+
+/// Finds the smallest circle enclosing every point using Welzl's algorithm (in its iterative form, over a pseudo-randomly shuffled copy of the points),
+/// which runs in expected linear time. An empty input gives a zero-radius circle at the origin.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::smallest_enclosing_circle;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(2f64, 1f64)];
+/// 	let circle = smallest_enclosing_circle(&points);
+/// 	assert_eq!(circle.center, Vec2D::new(2f64, 0f64));
+/// 	assert_eq!(circle.radius, 2f64);
+/// ```
+pub fn smallest_enclosing_circle(points: &[Vec2D<f64>]) -> Circle<f64> {
+	let mut shuffled = points.to_vec();
+	for i in (1..shuffled.len()).rev() {
+		let j = lattice_hash(i as i32, 0, 0x5eed) as usize % (i + 1);
+		shuffled.swap(i, j);
+	}
+
+	let mut circle = match shuffled.first() {
+		Some(&first) => Circle::new(first, 0f64),
+		None => return Circle::default()
+	};
+	for i in 1..shuffled.len() {
+		if encloses(&circle, shuffled[i]) {
+			continue;
+		}
+		circle = Circle::new(shuffled[i], 0f64);
+		for j in 0..i {
+			if encloses(&circle, shuffled[j]) {
+				continue;
+			}
+			circle = diametral_circle(shuffled[i], shuffled[j]);
+			for k in 0..j {
+				if !encloses(&circle, shuffled[k]) {
+					circle = circle_from_three(shuffled[i], shuffled[j], shuffled[k]);
+				}
+			}
+		}
+	}
+	circle
+}
+
+/// the closest pair among `indices` (sorted by x on entry), leaving `indices` sorted by y on exit
+fn closest_pair_recursive(points: &[Vec2D<f64>], indices: &mut [usize]) -> Option<(usize, usize, f64)> {
+	let distance = |i: usize, j: usize| (points[i] - points[j]).magnitude();
+	let closer = |best: Option<(usize, usize, f64)>, i: usize, j: usize| {
+		let d = distance(i, j);
+		match best {
+			Some((_, _, best_d)) if best_d <= d => best,
+			_ => Some((i, j, d))
+		}
+	};
+
+	if indices.len() <= 3 {
+		let mut best = None;
+		for a in 0..indices.len() {
+			for b in a + 1..indices.len() {
+				best = closer(best, indices[a], indices[b]);
+			}
+		}
+		indices.sort_by(|&i, &j| points[i].y.total_cmp(&points[j].y));
+		return best;
+	}
+
+	let mid = indices.len() / 2;
+	let split_x = points[indices[mid]].x;
+	let mut best = {
+		let (left, right) = indices.split_at_mut(mid);
+		let left_best = closest_pair_recursive(points, left);
+		let right_best = closest_pair_recursive(points, right);
+		match (left_best, right_best) {
+			(Some(l), Some(r)) => Some(if l.2 <= r.2 { l } else { r }),
+			(l, r) => l.or(r)
+		}
+	};
+
+	// merge the two y-sorted halves
+	let mut merged = Vec::with_capacity(indices.len());
+	{
+		let (left, right) = indices.split_at(mid);
+		let (mut l, mut r) = (0, 0);
+		while l < left.len() || r < right.len() {
+			if r == right.len() || (l < left.len() && points[left[l]].y <= points[right[r]].y) {
+				merged.push(left[l]);
+				l += 1;
+			} else {
+				merged.push(right[r]);
+				r += 1;
+			}
+		}
+	}
+	indices.copy_from_slice(&merged);
+
+	// only points within the current best distance of the split line can form a closer pair across it,
+	// and each only needs comparing against the few that follow it by y
+	let best_distance = best.map_or(f64::INFINITY, |pair| pair.2);
+	let strip: Vec<usize> = indices.iter().cloned().filter(|&i| (points[i].x - split_x).abs() < best_distance).collect();
+	for a in 0..strip.len() {
+		for b in a + 1..strip.len() {
+			if points[strip[b]].y - points[strip[a]].y >= best.map_or(f64::INFINITY, |pair| pair.2) {
+				break;
+			}
+			best = closer(best, strip[a], strip[b]);
+		}
+	}
+	best
+}
+
+/// Finds the two closest points using the divide-and-conquer `O(n log n)` algorithm,
+/// returning their indices (lowest first) and the distance between them, or `None` for fewer than two points.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::closest_pair;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(5f64, 5f64), Vec2D::new(1f64, 0f64), Vec2D::new(9f64, 0f64)];
+/// 	assert_eq!(closest_pair(&points), Some((0, 2, 1f64)));
+/// 	assert_eq!(closest_pair(&points[..1]), None);
+/// ```
+pub fn closest_pair(points: &[Vec2D<f64>]) -> Option<(usize, usize, f64)> {
+	let mut indices: Vec<usize> = (0..points.len()).collect();
+	indices.sort_by(|&i, &j| points[i].x.total_cmp(&points[j].x));
+	closest_pair_recursive(points, &mut indices).map(|(i, j, d)| (i.min(j), i.max(j), d))
+}
+
+/// the minimum and maximum coordinates over a non-empty set of points
+fn extents(points: &[Vec2D<f64>]) -> (Vec2D<f64>, Vec2D<f64>) {
+	points.iter().fold((points[0], points[0]), |(min, max), p| {
+		(Vec2D::new(min.x.min(p.x), min.y.min(p.y)), Vec2D::new(max.x.max(p.x), max.y.max(p.y)))
+	})
+}
+
+/// Triangulates `points` using the Bowyer-Watson algorithm, returning counter-clockwise triangles as triples of indices into `points`.
+/// The result is Delaunay: no input point lies strictly inside the circumcircle of any triangle.
+/// Duplicate points are only used once, and inputs with fewer than three non-collinear points give no triangles.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::delaunay;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 1f64), Vec2D::new(0f64, 3f64)];
+/// 	let triangles = delaunay(&points);
+/// 	assert_eq!(triangles.len(), 2);
+/// 	assert!(triangles.contains(&[0, 1, 2]));
+/// 	assert!(triangles.contains(&[0, 2, 3]));
+/// ```
+pub fn delaunay(points: &[Vec2D<f64>]) -> Vec<[usize; 3]> {
+	if points.len() < 3 {
+		return Vec::new();
+	}
+	let (min, max) = extents(points);
+	let center = (min + max) / 2f64;
+	let size = (max.x - min.x).max(max.y - min.y).max(1f64);
+
+	// a super triangle enclosing every point, whose vertices follow the real ones; it is made much larger than the points so
+	// its vertices do not fall inside the circumcircles of triangles along the convex hull, which would leave gaps there
+	let mut vertices = points.to_vec();
+	vertices.push(center + Vec2D::new(-1000f64 * size, -500f64 * size));
+	vertices.push(center + Vec2D::new(1000f64 * size, -500f64 * size));
+	vertices.push(center + Vec2D::new(0f64, 1000f64 * size));
+	let super_start = points.len();
+
+	let mut triangles: Vec<([usize; 3], Option<Circle<f64>>)> = vec![([super_start, super_start + 1, super_start + 2], circumcircle(vertices[super_start], vertices[super_start + 1], vertices[super_start + 2]))];
+	for (index, &point) in points.iter().enumerate() {
+		if points[..index].contains(&point) {
+			continue;
+		}
+		let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|&(_, circle)| match circle {
+			Some(circle) => (point - circle.center).magnitude() < circle.radius - CIRCLE_TOLERANCE * circle.radius.max(1f64),
+			None => false
+		});
+		triangles = good;
+
+		// the boundary of the cavity is made of the edges belonging to exactly one bad triangle
+		let edges: Vec<(usize, usize)> = bad.iter().flat_map(|&(t, _)| vec![(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]).collect();
+		for &(a, b) in &edges {
+			let shared = edges.iter().filter(|&&(c, d)| (c == a && d == b) || (c == b && d == a)).count() > 1;
+			if !shared {
+				triangles.push(([a, b, index], circumcircle(vertices[a], vertices[b], point)));
+			}
+		}
+	}
+
+	triangles.into_iter()
+		.map(|(t, _)| t)
+		.filter(|t| t.iter().all(|&v| v < super_start) && Vec2D::cross_product(points[t[1]] - points[t[0]], points[t[2]] - points[t[0]]) != 0f64)
+		.map(|t| {
+			let ccw = if Vec2D::cross_product(points[t[1]] - points[t[0]], points[t[2]] - points[t[0]]) > 0f64 { t } else { [t[0], t[2], t[1]] };
+			// rotate so the lowest index leads, giving a canonical form
+			let lowest = (0..3).min_by_key(|&i| ccw[i]).unwrap();
+			[ccw[lowest], ccw[(lowest + 1) % 3], ccw[(lowest + 2) % 3]]
+		})
+		.collect()
+}
+
+/// clips the convex polygon `cell` to the half-plane of points at least as close to `site` as to `other`
+fn clip_to_bisector(cell: &[Vec2D<f64>], site: Vec2D<f64>, other: Vec2D<f64>) -> Vec<Vec2D<f64>> {
+	let midpoint = (site + other) / 2f64;
+	let normal = other - site;
+	let side = |p: Vec2D<f64>| Vec2D::dot_product(p - midpoint, normal);
+	let mut clipped = Vec::with_capacity(cell.len() + 1);
+	for i in 0..cell.len() {
+		let (current, next) = (cell[i], cell[(i + 1) % cell.len()]);
+		let (current_side, next_side) = (side(current), side(next));
+		if current_side <= 0f64 {
+			clipped.push(current);
+		}
+		if (current_side < 0f64 && next_side > 0f64) || (current_side > 0f64 && next_side < 0f64) {
+			clipped.push(current + (next - current) * (current_side / (current_side - next_side)));
+		}
+	}
+	clipped
+}
+
+/// Builds the Voronoi cell of each point as a counter-clockwise polygon, from the dual of the Delaunay triangulation.
+/// Cells are clipped to the points' bounding box grown on every side by the larger of its width and height (and at least 1),
+/// and `cells[i]` is the region closer to `points[i]` than to any other point.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::voronoi_cells;
+/// 	let cells = voronoi_cells(&[Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64)]);
+/// 	assert_eq!(cells.len(), 2);
+/// 	assert!(cells[0].iter().all(|p| p.x <= 1f64));
+/// 	assert!(cells[1].iter().all(|p| p.x >= 1f64));
+/// ```
+pub fn voronoi_cells(points: &[Vec2D<f64>]) -> Vec<Vec<Vec2D<f64>>> {
+	if points.is_empty() {
+		return Vec::new();
+	}
+	let (min, max) = extents(points);
+	let margin = (max.x - min.x).max(max.y - min.y).max(1f64);
+	let (min, max) = (min - Vec2D::new(margin, margin), max + Vec2D::new(margin, margin));
+	let bounds = vec![min, Vec2D::new(max.x, min.y), max, Vec2D::new(min.x, max.y)];
+
+	// each cell is bounded by the bisectors with its Delaunay neighbours; without a triangulation (e.g. collinear points) every other point is used
+	let mut neighbours = vec![Vec::new(); points.len()];
+	let triangles = delaunay(points);
+	if triangles.is_empty() {
+		for (i, list) in neighbours.iter_mut().enumerate() {
+			list.extend((0..points.len()).filter(|&j| j != i));
+		}
+	} else {
+		for t in &triangles {
+			for k in 0..3 {
+				let (a, b) = (t[k], t[(k + 1) % 3]);
+				neighbours[a].push(b);
+				neighbours[b].push(a);
+			}
+		}
+	}
+
+	points.iter().enumerate().map(|(i, &site)| {
+		let mut cell = bounds.clone();
+		for &j in &neighbours[i] {
+			if points[j] != site {
+				cell = clip_to_bisector(&cell, site, points[j]);
+			}
+		}
+		cell
+	}).collect()
+}
+
+/// Removes near-duplicate points, keeping the first of any points within `epsilon` of an already kept point.
+/// Points are bucketed into a spatial hash of `epsilon`-sized cells, so only neighbouring cells are compared.
+/// A non-positive `epsilon` removes exact duplicates only. The order of the kept points is preserved.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::dedup_points;
+/// 	let points = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 0.001)];
+/// 	assert_eq!(dedup_points(&points, 0.01), vec![Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64)]);
+/// ```
+pub fn dedup_points(points: &[Vec2D<f64>], epsilon: f64) -> Vec<Vec2D<f64>> {
+	if epsilon <= 0f64 {
+		let mut seen = HashSet::new();
+		return points.iter().cloned().filter(|p| seen.insert((p.x.to_bits(), p.y.to_bits()))).collect();
+	}
+	let cell_of = |p: Vec2D<f64>| ((p.x / epsilon).floor() as i64, (p.y / epsilon).floor() as i64);
+	let mut cells: HashMap<(i64, i64), Vec<Vec2D<f64>>> = HashMap::new();
+	let mut kept = Vec::new();
+	for &p in points {
+		let (cx, cy) = cell_of(p);
+		let duplicate = (cx - 1..=cx + 1).any(|x| (cy - 1..=cy + 1).any(|y| {
+			cells.get(&(x, y)).is_some_and(|bucket| bucket.iter().any(|&q| (p - q).magnitude() <= epsilon))
+		}));
+		if !duplicate {
+			cells.entry((cx, cy)).or_default().push(p);
+			kept.push(p);
+		}
+	}
+	kept
+}
+
+/// Sorts `points` by x and then y using `f64::total_cmp`, a total order over every bit pattern, so the result is the same on
+/// every platform and for every starting order, including with NaNs (sorted after the numbers, or before them when negative)
+/// and signed zeros (`-0` before `0`). Useful wherever iteration order must be reproducible, as in lockstep simulations.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::sort_points_deterministic;
+/// 	let mut points = [Vec2D::new(1f64, 0f64), Vec2D::new(f64::NAN, 0f64), Vec2D::new(0f64, 2f64), Vec2D::new(0f64, 1f64)];
+/// 	sort_points_deterministic(&mut points);
+/// 	assert_eq!(&points[..3], &[Vec2D::new(0f64, 1f64), Vec2D::new(0f64, 2f64), Vec2D::new(1f64, 0f64)]);
+/// 	assert!(points[3].x.is_nan());
+/// ```
+pub fn sort_points_deterministic(points: &mut [Vec2D<f64>]) {
+	points.sort_unstable_by(|a, b| a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y)));
+}
+
+/// Finds the two points on `circle` touched by the tangent lines through `from`, or `None` if `from` is inside the circle.
+/// The first point is counter-clockwise about the center from the second; a point on the circle is its own (double) tangent point.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::Circle;
+/// 	use phys2d::geometry::tangent_points;
+/// 	let circle = Circle::new(Vec2D::new(0f64, 0f64), 1f64);
+/// 	let (a, b) = tangent_points(&circle, Vec2D::new(2f64.sqrt(), 0f64)).unwrap();
+/// 	assert!((a - Vec2D::new(0.5f64.sqrt(), 0.5f64.sqrt())).magnitude() < 1e-12);
+/// 	assert!((b - Vec2D::new(0.5f64.sqrt(), -0.5f64.sqrt())).magnitude() < 1e-12);
+/// ```
+pub fn tangent_points(circle: &Circle<f64>, from: Vec2D<f64>) -> Option<(Vec2D<f64>, Vec2D<f64>)> {
+	let offset = from - circle.center;
+	let distance = offset.magnitude();
+	if distance < circle.radius || distance == 0f64 {
+		return None;
+	}
+	// each tangent point sits at this angle either side of the direction towards `from`, as seen from the center
+	let spread = (circle.radius / distance).acos();
+	let towards = offset * (circle.radius / distance);
+	Some((circle.center + towards.rotate(spread), circle.center + towards.rotate(-spread)))
+}
+
+/// Finds where the boundaries of two circles cross: two points, one where they touch, or none if they are apart or one is inside the other.
+/// Identical circles share their whole boundary, which can't be returned as points, so they also give none.
+/// When there are two points, the first is on the left of the line from `a`'s center to `b`'s.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::Circle;
+/// 	use phys2d::geometry::circle_circle_intersections;
+/// 	let a = Circle::new(Vec2D::new(0f64, 0f64), 5f64);
+/// 	let b = Circle::new(Vec2D::new(8f64, 0f64), 5f64);
+/// 	assert_eq!(circle_circle_intersections(&a, &b), vec![Vec2D::new(4f64, 3f64), Vec2D::new(4f64, -3f64)]);
+/// ```
+pub fn circle_circle_intersections(a: &Circle<f64>, b: &Circle<f64>) -> Vec<Vec2D<f64>> {
+	let offset = b.center - a.center;
+	let distance = offset.magnitude();
+	if distance == 0f64 || distance > a.radius + b.radius || distance < (a.radius - b.radius).abs() {
+		return Vec::new();
+	}
+	// the crossing points sit either side of the line between the centers, `along` from `a`'s center
+	let along = (distance * distance + a.radius * a.radius - b.radius * b.radius) / (2f64 * distance);
+	let half_chord = (a.radius * a.radius - along * along).max(0f64).sqrt();
+	let direction = offset / distance;
+	let midpoint = a.center + direction * along;
+	if half_chord <= CIRCLE_TOLERANCE * a.radius.max(1f64) {
+		return vec![midpoint];
+	}
+	let across = direction.perp() * half_chord;
+	vec![midpoint + across, midpoint - across]
+}
+
+/// relative slack allowed between the ranges given to `trilaterate` and the distances to the point it finds
+const TRILATERATION_TOLERANCE: f64 = 1e-6;
+
+/// Finds the point at distance `r1` from `p1`, `r2` from `p2` and `r3` from `p3`.
+/// Returns `None` if the anchors are collinear (so there could be two such points), or if the ranges are inconsistent and no point fits all three.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::trilaterate;
+/// 	let found = trilaterate(Vec2D::new(0f64, 0f64), 5f64, Vec2D::new(8f64, 0f64), 5f64, Vec2D::new(4f64, 6f64), 3f64);
+/// 	assert!((found.unwrap() - Vec2D::new(4f64, 3f64)).magnitude() < 1e-12);
+/// ```
+pub fn trilaterate(p1: Vec2D<f64>, r1: f64, p2: Vec2D<f64>, r2: f64, p3: Vec2D<f64>, r3: f64) -> Option<Vec2D<f64>> {
+	// subtracting the first circle's equation from the others leaves two linear equations, relative to `p1`
+	let (a, b) = (p2 - p1, p3 - p1);
+	let determinant = Vec2D::cross_product(a, b);
+	if determinant == 0f64 {
+		return None;
+	}
+	let c1 = (r1 * r1 - r2 * r2 + Vec2D::dot_product(a, a)) / 2f64;
+	let c2 = (r1 * r1 - r3 * r3 + Vec2D::dot_product(b, b)) / 2f64;
+	let point = p1 + Vec2D::new(c1 * b.y - c2 * a.y, a.x * c2 - b.x * c1) / determinant;
+	let fits = [(p1, r1), (p2, r2), (p3, r3)].iter()
+		.all(|&(anchor, range)| ((point - anchor).magnitude() - range).abs() <= TRILATERATION_TOLERANCE * range.max(1f64));
+	if fits { Some(point) } else { None }
+}
+
+/// iterations of `closest_point_on_ellipse`'s refinement, which converges to well within floating point precision in far fewer
+const ELLIPSE_ITERATIONS: usize = 12;
+
+/// Finds the point on the boundary of the axis-aligned ellipse at `center` with semi-axes `radii` nearest to `point`
+/// (which may be inside or outside it). Working in the first quadrant by symmetry, the point's parameter on the ellipse is
+/// refined iteratively: each step approximates the ellipse near the current estimate by its circle of curvature (centred on
+/// the evolute) and moves to where the line from that centre to `point` meets it, a Newton-like step that converges quickly
+/// and (unlike Newton's method on the angle directly) stays stable even for very elongated ellipses.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::closest_point_on_ellipse;
+/// 	let nearest = closest_point_on_ellipse(Vec2D::new(1f64, 10f64), Vec2D::new(1f64, 0f64), Vec2D::new(4f64, 2f64));
+/// 	assert!((nearest - Vec2D::new(1f64, 2f64)).magnitude() < 1e-9);
+/// ```
+pub fn closest_point_on_ellipse(point: Vec2D<f64>, center: Vec2D<f64>, radii: Vec2D<f64>) -> Vec2D<f64> {
+	let local = point - center;
+	let (a, b) = (radii.x.abs(), radii.y.abs());
+	let (px, py) = (local.x.abs(), local.y.abs());
+	let (mut tx, mut ty) = (std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2);
+	for _ in 0..ELLIPSE_ITERATIONS {
+		let (x, y) = (a * tx, b * ty);
+		// the centre of curvature of the ellipse at (x, y)
+		let ex = (a * a - b * b) * tx * tx * tx / a;
+		let ey = (b * b - a * a) * ty * ty * ty / b;
+		let (rx, ry) = (x - ex, y - ey);
+		let (qx, qy) = (px - ex, py - ey);
+		let (r, q) = (rx.hypot(ry), qx.hypot(qy));
+		if q == 0f64 {
+			break;
+		}
+		tx = ((qx * r / q + ex) / a).clamp(0f64, 1f64);
+		ty = ((qy * r / q + ey) / b).clamp(0f64, 1f64);
+		let t = tx.hypot(ty);
+		tx /= t;
+		ty /= t;
+	}
+	center + Vec2D::new((a * tx).copysign(local.x), (b * ty).copysign(local.y))
+}
+
+/// Outlines the point set with an alpha shape, a concave hull that follows indentations the convex hull would bridge over.
+/// Triangles of the `delaunay` triangulation with a circumradius of at most `alpha` are kept, and the result is the
+/// counter-clockwise boundary of their union: small values of `alpha` hug the points closely (and may break the shape apart),
+/// while large ones approach the convex hull. If the kept triangles form several outlines the largest is returned, ignoring holes;
+/// if none are kept the result is empty.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::geometry::concave_hull;
+/// 	use phys2d::polygon::signed_area;
+/// 	// an L shape, whose notch the convex hull would fill in
+/// 	let points: Vec<Vec2D<f64>> = (0..3).flat_map(|x| (0..3).map(move |y| Vec2D::new(x as f64, y as f64)))
+/// 		.filter(|p| p.x < 1.5 || p.y < 0.5).collect();
+/// 	let hull = concave_hull(&points, 1f64);
+/// 	assert_eq!(signed_area(&hull), 2.5);
+/// ```
+pub fn concave_hull(points: &[Vec2D<f64>], alpha: f64) -> Vec<Vec2D<f64>> {
+	let kept: Vec<[usize; 3]> = delaunay(points).into_iter()
+		.filter(|t| circumcircle(points[t[0]], points[t[1]], points[t[2]]).is_some_and(|c| c.radius <= alpha)).collect();
+
+	// an edge is on the boundary if only one kept triangle has it; the triangles are counter-clockwise, so following
+	// their boundary edges in their own direction walks the outline counter-clockwise too
+	let edges: HashSet<(usize, usize)> = kept.iter().flat_map(|t| vec![(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]).collect();
+	let mut next: HashMap<usize, Vec<usize>> = HashMap::new();
+	for &(a, b) in edges.iter() {
+		if !edges.contains(&(b, a)) {
+			next.entry(a).or_default().push(b);
+		}
+	}
+	for targets in next.values_mut() {
+		targets.sort_unstable();
+	}
+
+	let mut best: Vec<Vec2D<f64>> = Vec::new();
+	let mut starts: Vec<usize> = next.keys().cloned().collect();
+	starts.sort_unstable();
+	for start in starts {
+		let mut outline = Vec::new();
+		let mut current = start;
+		while let Some(following) = next.get_mut(&current).and_then(|targets| targets.pop()) {
+			outline.push(points[current]);
+			current = following;
+			if current == start {
+				break;
+			}
+		}
+		if outline.len() >= 3 && signed_area(&outline) > signed_area(&best) {
+			best = outline;
+		}
+	}
+	best
+}
@@ -0,0 +1,198 @@
+use std::collections::{HashSet, VecDeque};
+use vec2d::Vec2D;
+use raster::bresenham_line;
+use shapes::AABB;
+
+impl Vec2D<i32> {
+	/// The taxicab distance to `other`: the number of orthogonal grid steps between the two cells.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1, 2).manhattan_distance(Vec2D::new(4, -2)), 7);
+	/// ```
+	pub fn manhattan_distance(self, other: Vec2D<i32>) -> i32 {
+		(self.x - other.x).abs() + (self.y - other.y).abs()
+	}
+
+	/// The chessboard distance to `other`: the number of grid steps between the two cells when diagonal steps are allowed.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1, 2).chebyshev_distance(Vec2D::new(4, -2)), 4);
+	/// ```
+	pub fn chebyshev_distance(self, other: Vec2D<i32>) -> i32 {
+		(self.x - other.x).abs().max((self.y - other.y).abs())
+	}
+
+	/// The four orthogonally adjacent cells, in the order north, east, south, west (taking north as `-y`, as for rows of tiles).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(0, 0).neighbors4(), [Vec2D::new(0, -1), Vec2D::new(1, 0), Vec2D::new(0, 1), Vec2D::new(-1, 0)]);
+	/// ```
+	pub fn neighbors4(&self) -> [Vec2D<i32>; 4] {
+		let (x, y) = (self.x, self.y);
+		[Vec2D::new(x, y - 1), Vec2D::new(x + 1, y), Vec2D::new(x, y + 1), Vec2D::new(x - 1, y)]
+	}
+
+	/// The eight surrounding cells, clockwise from north (taking north as `-y`): north, north-east, east, south-east, south, south-west, west, north-west.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let around = Vec2D::new(5, 5).neighbors8();
+	/// 	assert_eq!(around[1], Vec2D::new(6, 4));
+	/// 	assert!(around.iter().all(|c| c.chebyshev_distance(Vec2D::new(5, 5)) == 1));
+	/// ```
+	pub fn neighbors8(&self) -> [Vec2D<i32>; 8] {
+		let (x, y) = (self.x, self.y);
+		[
+			Vec2D::new(x, y - 1), Vec2D::new(x + 1, y - 1), Vec2D::new(x + 1, y), Vec2D::new(x + 1, y + 1),
+			Vec2D::new(x, y + 1), Vec2D::new(x - 1, y + 1), Vec2D::new(x - 1, y), Vec2D::new(x - 1, y - 1)
+		]
+	}
+}
+
+/// Checks whether `to` can be seen from `from`: true unless some cell strictly between them on their Bresenham line `is_blocking`.
+/// The endpoints themselves are never treated as blocking, so e.g. a wall cell can still be "seen" when targeted directly.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::grid::has_line_of_sight;
+/// 	let wall = |c: Vec2D<i32>| c.x == 2;
+/// 	assert!(!has_line_of_sight(Vec2D::new(0, 0), Vec2D::new(4, 1), wall));
+/// 	assert!(has_line_of_sight(Vec2D::new(0, 0), Vec2D::new(1, 5), wall));
+/// ```
+pub fn has_line_of_sight(from: Vec2D<i32>, to: Vec2D<i32>, is_blocking: impl Fn(Vec2D<i32>) -> bool) -> bool {
+	bresenham_line(from, to).filter(|&cell| cell != from && cell != to).all(|cell| !is_blocking(cell))
+}
+
+/// octant transforms (xx, xy, yx, yy) mapping shadowcasting's local row/column space onto the grid
+const OCTANTS: [(i32, i32, i32, i32); 8] = [
+	(1, 0, 0, 1), (0, 1, 1, 0), (0, -1, 1, 0), (-1, 0, 0, 1),
+	(-1, 0, 0, -1), (0, -1, -1, 0), (0, 1, -1, 0), (1, 0, 0, -1),
+];
+
+#[allow(clippy::too_many_arguments)]
+fn cast_light<F: Fn(Vec2D<i32>) -> bool>(origin: Vec2D<i32>, radius: i32, row: i32, mut start: f64, end: f64, octant: (i32, i32, i32, i32), is_opaque: &F, visible: &mut HashSet<Vec2D<i32>>) {
+	if start < end {
+		return;
+	}
+	let (xx, xy, yx, yy) = octant;
+	let mut next_start = start;
+	for distance in row..radius + 1 {
+		let dy = -distance;
+		let mut blocked = false;
+		for dx in -distance..1 {
+			let cell = Vec2D::new(origin.x + dx * xx + dy * xy, origin.y + dx * yx + dy * yy);
+			let (left_slope, right_slope) = ((dx as f64 - 0.5) / (dy as f64 + 0.5), (dx as f64 + 0.5) / (dy as f64 - 0.5));
+			if start < right_slope {
+				continue;
+			}
+			if end > left_slope {
+				break;
+			}
+			if dx * dx + dy * dy <= radius * radius {
+				visible.insert(cell);
+			}
+			if blocked {
+				if is_opaque(cell) {
+					next_start = right_slope;
+				} else {
+					blocked = false;
+					start = next_start;
+				}
+			} else if is_opaque(cell) && distance < radius {
+				blocked = true;
+				cast_light(origin, radius, distance + 1, start, left_slope, octant, is_opaque, visible);
+				next_start = right_slope;
+			}
+		}
+		if blocked {
+			break;
+		}
+	}
+}
+
+/// Computes the set of cells visible from `origin` within `radius` (euclidean) using recursive shadowcasting.
+/// Opaque cells are themselves visible but hide whatever lies behind them; the origin is always visible.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::grid::compute_fov;
+/// 	let visible = compute_fov(Vec2D::new(0, 0), 5, |c| c == Vec2D::new(2, 0));
+/// 	assert!(visible.contains(&Vec2D::new(2, 0)));
+/// 	assert!(!visible.contains(&Vec2D::new(4, 0)));
+/// ```
+pub fn compute_fov(origin: Vec2D<i32>, radius: i32, is_opaque: impl Fn(Vec2D<i32>) -> bool) -> HashSet<Vec2D<i32>> {
+	let mut visible = HashSet::new();
+	visible.insert(origin);
+	for &octant in &OCTANTS {
+		cast_light(origin, radius, 1, 1f64, 0f64, octant, &is_opaque, &mut visible);
+	}
+	visible
+}
+
+/// Iterates over every integer cell in `bounds` in row-major order (along x, then up a row),
+/// including both `bounds.min` and `bounds.max` (like `AABB::contains`); bounds with `min` past `max` on either axis yield no cells.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::grid::grid_cells;
+/// 	let cells: Vec<_> = grid_cells(&AABB::new(Vec2D::new(0, 0), Vec2D::new(1, 1))).collect();
+/// 	assert_eq!(cells, vec![Vec2D::new(0, 0), Vec2D::new(1, 0), Vec2D::new(0, 1), Vec2D::new(1, 1)]);
+/// ```
+pub fn grid_cells(bounds: &AABB<i32>) -> impl Iterator<Item = Vec2D<i32>> {
+	let (min, max) = (bounds.min, bounds.max);
+	(min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Vec2D::new(x, y)))
+}
+
+/// Finds every cell connected to `start` through cells that satisfy `matches` (4-way, or 8-way with `diagonal`).
+/// The result is empty if `start` itself doesn't match. `matches` must reject every cell on some boundary around `start`,
+/// as an unbounded region would never finish filling.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::grid::flood_fill;
+/// 	let inside = |c: Vec2D<i32>| c.x.abs() <= 1 && c.y.abs() <= 1;
+/// 	assert_eq!(flood_fill(Vec2D::new(0, 0), inside, false).len(), 9);
+/// ```
+pub fn flood_fill(start: Vec2D<i32>, matches: impl Fn(Vec2D<i32>) -> bool, diagonal: bool) -> HashSet<Vec2D<i32>> {
+	let mut filled = HashSet::new();
+	if !matches(start) {
+		return filled;
+	}
+	let mut frontier = VecDeque::new();
+	filled.insert(start);
+	frontier.push_back(start);
+	while let Some(cell) = frontier.pop_front() {
+		let neighbors: &[Vec2D<i32>] = if diagonal { &cell.neighbors8() } else { &cell.neighbors4() };
+		for &next in neighbors {
+			if !filled.contains(&next) && matches(next) {
+				filled.insert(next);
+				frontier.push_back(next);
+			}
+		}
+	}
+	filled
+}
+
+/// Advances a Life-like cellular automaton by one generation over the cells of `bounds` (min and max both included, as in `grid_cells`).
+/// A live cell stays alive if its number of live 8-way neighbours is in `survive`, and a dead cell comes alive if it is in `born`;
+/// Conway's Game of Life is `survive = [2, 3]`, `born = [3]`. Cells outside `bounds` are always dead in the result.
+/// # Examples
+/// ```
+/// 	use std::collections::HashSet;
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::grid::cellular_step;
+/// 	let lonely: HashSet<_> = [Vec2D::new(2, 2)].iter().cloned().collect();
+/// 	let bounds = AABB::new(Vec2D::new(0, 0), Vec2D::new(4, 4));
+/// 	assert!(cellular_step(&lonely, &bounds, &[2, 3], &[3]).is_empty());
+/// ```
+pub fn cellular_step(alive: &HashSet<Vec2D<i32>>, bounds: &AABB<i32>, survive: &[usize], born: &[usize]) -> HashSet<Vec2D<i32>> {
+	grid_cells(bounds).filter(|cell| {
+		let count = cell.neighbors8().iter().filter(|n| alive.contains(n)).count();
+		if alive.contains(cell) { survive.contains(&count) } else { born.contains(&count) }
+	}).collect()
+}
@@ -0,0 +1,68 @@
+use vec2d::Vec2D;
+
+/// the axial offsets of a hex's six neighbours, counter-clockwise from the east
+const NEIGHBOUR_OFFSETS: [(i32, i32); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+/// Converts axial hex coordinates (`x` = q, `y` = r) to the pixel position of the hex's centre,
+/// for a pointy-top layout with hexes of circumradius `size` and hex (0, 0) centred at the origin.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::hex::hex_to_pixel;
+/// 	assert_eq!(hex_to_pixel(Vec2D::new(0, 2), 1f64).y, 3f64);
+/// ```
+pub fn hex_to_pixel(hex: Vec2D<i32>, size: f64) -> Vec2D<f64> {
+	let (q, r) = (f64::from(hex.x), f64::from(hex.y));
+	let sqrt3 = 3f64.sqrt();
+	Vec2D::new(size * (sqrt3 * q + sqrt3 / 2f64 * r), size * 1.5 * r)
+}
+
+/// Finds the axial coordinates of the (pointy-top, circumradius `size`) hex containing the pixel position `pixel`; the inverse of `hex_to_pixel`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::hex::{hex_to_pixel, pixel_to_hex};
+/// 	let hex = Vec2D::new(3, -5);
+/// 	assert_eq!(pixel_to_hex(hex_to_pixel(hex, 10f64) + Vec2D::new(2f64, -3f64), 10f64), hex);
+/// ```
+pub fn pixel_to_hex(pixel: Vec2D<f64>, size: f64) -> Vec2D<i32> {
+	let q = (3f64.sqrt() / 3f64 * pixel.x - pixel.y / 3f64) / size;
+	let r = (2f64 / 3f64 * pixel.y) / size;
+	// round in cube coordinates (q + r + s = 0), fixing up whichever component rounded furthest
+	let s = -q - r;
+	let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+	let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+	if dq > dr && dq > ds {
+		rq = -rr - rs;
+	} else if dr > ds {
+		rr = -rq - rs;
+	}
+	Vec2D::new(rq as i32, rr as i32)
+}
+
+/// The number of steps between two hexes given in axial coordinates.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::hex::hex_distance;
+/// 	assert_eq!(hex_distance(Vec2D::new(0, 0), Vec2D::new(2, -3)), 3);
+/// ```
+pub fn hex_distance(a: Vec2D<i32>, b: Vec2D<i32>) -> i32 {
+	let (dq, dr) = (a.x - b.x, a.y - b.y);
+	(dq.abs() + dr.abs() + (dq + dr).abs()) / 2
+}
+
+/// The six hexes adjacent to `hex` (axial coordinates), counter-clockwise from the east.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::hex::hex_neighbors;
+/// 	assert_eq!(hex_neighbors(Vec2D::new(0, 0))[0], Vec2D::new(1, 0));
+/// ```
+pub fn hex_neighbors(hex: Vec2D<i32>) -> [Vec2D<i32>; 6] {
+	let mut neighbours = [hex; 6];
+	for (neighbour, &(dq, dr)) in neighbours.iter_mut().zip(NEIGHBOUR_OFFSETS.iter()) {
+		*neighbour = Vec2D::new(hex.x + dq, hex.y + dr);
+	}
+	neighbours
+}
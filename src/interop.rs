@@ -0,0 +1,27 @@
+//! conversions to and from the vector types of other math crates, each behind a feature of the same name
+use vec2d::Vec2D;
+use glam;
+
+impl From<Vec2D<f32>> for glam::Vec2 {
+	fn from(v: Vec2D<f32>) -> glam::Vec2 {
+		glam::Vec2::new(v.x, v.y)
+	}
+}
+
+impl From<glam::Vec2> for Vec2D<f32> {
+	fn from(v: glam::Vec2) -> Vec2D<f32> {
+		Vec2D::new(v.x, v.y)
+	}
+}
+
+impl From<Vec2D<f64>> for glam::DVec2 {
+	fn from(v: Vec2D<f64>) -> glam::DVec2 {
+		glam::DVec2::new(v.x, v.y)
+	}
+}
+
+impl From<glam::DVec2> for Vec2D<f64> {
+	fn from(v: glam::DVec2) -> Vec2D<f64> {
+		Vec2D::new(v.x, v.y)
+	}
+}
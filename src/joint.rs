@@ -0,0 +1,109 @@
+use vec2d::Vec2D;
+use rigid_body::{RigidBody2D, pair_mut, torque, velocity_at_point};
+
+/// the fraction of a joint's positional error that `solve` feeds back into the velocities each step
+const BAUMGARTE: f64 = 0.2;
+
+/// a constraint holding the anchor points of two bodies `rest_length` apart, like a rigid rod between them
+/// Note: the anchors are offsets from each body's position in its own (unrotated) frame, so they turn with the body
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct DistanceJoint {
+	/// the index of the first body in the slice passed to `solve`
+	pub body_a: usize,
+	/// the index of the second body in the slice passed to `solve`
+	pub body_b: usize,
+	pub anchor_a: Vec2D<f64>,
+	pub anchor_b: Vec2D<f64>,
+	pub rest_length: f64
+}
+
+impl DistanceJoint {
+	/// Creates a joint holding the two anchors `rest_length` apart.
+	pub fn new(body_a: usize, body_b: usize, anchor_a: Vec2D<f64>, anchor_b: Vec2D<f64>, rest_length: f64) -> DistanceJoint {
+		DistanceJoint{body_a, body_b, anchor_a, anchor_b, rest_length}
+	}
+
+	/// Applies equal and opposite impulses at the two anchors so that they stop moving apart along the joint,
+	/// plus a Baumgarte bias that corrects the accumulated distance error over the coming steps of length `dt`.
+	/// A zero `rest_length` is solved as a point constraint on both axes, as the joint then has no direction.
+	/// Calling this several times per step (before integrating the bodies) makes a chain of joints stiffer.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::rigid_body::RigidBody2D;
+	/// 	use phys2d::joint::DistanceJoint;
+	/// 	let mut bodies = [RigidBody2D::new(Vec2D::new(0f64, 0f64), 1f64, 1f64), RigidBody2D::new(Vec2D::new(2f64, 0f64), 1f64, 1f64)];
+	/// 	bodies[1].velocity = Vec2D::new(1f64, 0f64);
+	/// 	let joint = DistanceJoint::new(0, 1, Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64), 2f64);
+	/// 	joint.solve(&mut bodies, 0.1);
+	/// 	assert_eq!(bodies[0].velocity, Vec2D::new(0.5, 0f64));
+	/// 	assert_eq!(bodies[1].velocity, Vec2D::new(0.5, 0f64));
+	/// ```
+	pub fn solve(&self, bodies: &mut [RigidBody2D], dt: f64) {
+		if self.body_a == self.body_b || dt <= 0f64 {
+			return;
+		}
+		let (a, b) = pair_mut(bodies, self.body_a, self.body_b);
+		let (arm_a, arm_b) = (self.anchor_a.rotate(a.rotation), self.anchor_b.rotate(b.rotation));
+		let (point_a, point_b) = (a.position + arm_a, b.position + arm_b);
+		let relative_velocity = velocity_at_point(b.velocity, b.angular_velocity, b.position, point_b)
+			- velocity_at_point(a.velocity, a.angular_velocity, a.position, point_a);
+		let (inverse_mass_a, inverse_mass_b) = (a.inverse_mass(), b.inverse_mass());
+		let (inverse_inertia_a, inverse_inertia_b) = (a.inverse_inertia(), b.inverse_inertia());
+
+		let impulse = if self.rest_length == 0f64 {
+			// K = (1/ma + 1/mb)I + (1/Ia)(r⊥a r⊥aᵀ) + (1/Ib)(r⊥b r⊥bᵀ), solved for K·λ = -(Δv + bias)
+			let (perp_a, perp_b) = (arm_a.perp(), arm_b.perp());
+			let linear = inverse_mass_a + inverse_mass_b;
+			let k11 = linear + inverse_inertia_a * perp_a.x * perp_a.x + inverse_inertia_b * perp_b.x * perp_b.x;
+			let k12 = inverse_inertia_a * perp_a.x * perp_a.y + inverse_inertia_b * perp_b.x * perp_b.y;
+			let k22 = linear + inverse_inertia_a * perp_a.y * perp_a.y + inverse_inertia_b * perp_b.y * perp_b.y;
+			let determinant = k11 * k22 - k12 * k12;
+			if determinant == 0f64 {
+				return;
+			}
+			let target = -(relative_velocity + (point_b - point_a) * (BAUMGARTE / dt));
+			Vec2D::new(k22 * target.x - k12 * target.y, k11 * target.y - k12 * target.x) * (1f64 / determinant)
+		} else {
+			let offset = point_b - point_a;
+			let distance = offset.magnitude();
+			if distance == 0f64 {
+				return;
+			}
+			let normal = offset * (1f64 / distance);
+			let (arm_a_normal, arm_b_normal) = (torque(arm_a, normal), torque(arm_b, normal));
+			let effective_inverse_mass = inverse_mass_a + inverse_mass_b
+				+ arm_a_normal * arm_a_normal * inverse_inertia_a
+				+ arm_b_normal * arm_b_normal * inverse_inertia_b;
+			if effective_inverse_mass == 0f64 {
+				return;
+			}
+			let bias = (distance - self.rest_length) * BAUMGARTE / dt;
+			normal * (-(Vec2D::dot_product(relative_velocity, normal) + bias) / effective_inverse_mass)
+		};
+		a.apply_impulse(-impulse, point_a);
+		b.apply_impulse(impulse, point_b);
+	}
+}
+
+/// a constraint holding the anchor points of two bodies together, leaving the bodies free to rotate about it
+/// (a `DistanceJoint` with a `rest_length` of zero)
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct PinJoint {
+	pub body_a: usize,
+	pub body_b: usize,
+	pub anchor_a: Vec2D<f64>,
+	pub anchor_b: Vec2D<f64>
+}
+
+impl PinJoint {
+	/// Creates a joint pinning the two anchors together.
+	pub fn new(body_a: usize, body_b: usize, anchor_a: Vec2D<f64>, anchor_b: Vec2D<f64>) -> PinJoint {
+		PinJoint{body_a, body_b, anchor_a, anchor_b}
+	}
+
+	/// Applies the impulses pulling the anchors together over the coming steps of length `dt` (see `DistanceJoint::solve`).
+	pub fn solve(&self, bodies: &mut [RigidBody2D], dt: f64) {
+		DistanceJoint::new(self.body_a, self.body_b, self.anchor_a, self.anchor_b, 0f64).solve(bodies, dt);
+	}
+}
@@ -0,0 +1,114 @@
+use vec2d::Vec2D;
+
+/// a 2D k-d tree over a fixed set of points, for fast nearest-neighbour and radius queries;
+/// query results refer to points by their index in the slice the tree was built from
+/// Note: the tree is stored implicitly, with each node at the median of its range and its subtrees either side of it
+#[derive(Clone, Debug)]
+pub struct KdTree2D {
+	points: Vec<Vec2D<f64>>,
+	order: Vec<usize>
+}
+
+/// the coordinate a node at `depth` splits on (x at even depths, y at odd ones)
+fn axis_value(point: Vec2D<f64>, depth: usize) -> f64 {
+	if depth % 2 == 0 { point.x } else { point.y }
+}
+
+impl KdTree2D {
+	/// Builds a balanced tree over `points`.
+	pub fn new(points: &[Vec2D<f64>]) -> KdTree2D {
+		let mut order: Vec<usize> = (0..points.len()).collect();
+		KdTree2D::build(points, &mut order, 0);
+		KdTree2D{points: points.to_vec(), order}
+	}
+
+	fn build(points: &[Vec2D<f64>], order: &mut [usize], depth: usize) {
+		if order.len() <= 1 {
+			return;
+		}
+		let mid = order.len() / 2;
+		order.select_nth_unstable_by(mid, |&a, &b| axis_value(points[a], depth).total_cmp(&axis_value(points[b], depth)));
+		let (left, right) = order.split_at_mut(mid);
+		KdTree2D::build(points, left, depth + 1);
+		KdTree2D::build(points, &mut right[1..], depth + 1);
+	}
+
+	/// The number of points in the tree.
+	pub fn len(&self) -> usize {
+		self.points.len()
+	}
+
+	/// Whether the tree has no points.
+	pub fn is_empty(&self) -> bool {
+		self.points.is_empty()
+	}
+
+	/// Finds the point nearest to `query`, returning its index and distance.
+	/// Panics if the tree is empty.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::kdtree::KdTree2D;
+	/// 	let tree = KdTree2D::new(&[Vec2D::new(0f64, 0f64), Vec2D::new(5f64, 5f64), Vec2D::new(9f64, 1f64)]);
+	/// 	assert_eq!(tree.nearest(Vec2D::new(8f64, 1f64)), (2, 1f64));
+	/// ```
+	pub fn nearest(&self, query: Vec2D<f64>) -> (usize, f64) {
+		assert!(!self.is_empty(), "nearest requires a non-empty tree");
+		let mut best = (self.order[0], f64::INFINITY);
+		self.nearest_in(&self.order, 0, query, &mut best);
+		best
+	}
+
+	fn nearest_in(&self, order: &[usize], depth: usize, query: Vec2D<f64>, best: &mut (usize, f64)) {
+		if order.is_empty() {
+			return;
+		}
+		let mid = order.len() / 2;
+		let node = self.points[order[mid]];
+		let distance = (node - query).magnitude();
+		if distance < best.1 {
+			*best = (order[mid], distance);
+		}
+		let offset = axis_value(query, depth) - axis_value(node, depth);
+		let (near, far) = if offset < 0f64 { (&order[..mid], &order[mid + 1..]) } else { (&order[mid + 1..], &order[..mid]) };
+		self.nearest_in(near, depth + 1, query, best);
+		// the far side can only hold something closer if the splitting line is within the best distance
+		if offset.abs() < best.1 {
+			self.nearest_in(far, depth + 1, query, best);
+		}
+	}
+
+	/// Finds the indices of every point within `radius` of `query` (boundary included), in no particular order.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::kdtree::KdTree2D;
+	/// 	let tree = KdTree2D::new(&[Vec2D::new(0f64, 0f64), Vec2D::new(5f64, 5f64), Vec2D::new(1f64, 1f64)]);
+	/// 	let mut found = tree.within_radius(Vec2D::new(0f64, 0f64), 2f64);
+	/// 	found.sort();
+	/// 	assert_eq!(found, vec![0, 2]);
+	/// ```
+	pub fn within_radius(&self, query: Vec2D<f64>, radius: f64) -> Vec<usize> {
+		let mut found = Vec::new();
+		self.within_radius_in(&self.order, 0, query, radius, &mut found);
+		found
+	}
+
+	fn within_radius_in(&self, order: &[usize], depth: usize, query: Vec2D<f64>, radius: f64, found: &mut Vec<usize>) {
+		if order.is_empty() {
+			return;
+		}
+		let mid = order.len() / 2;
+		let node = self.points[order[mid]];
+		if (node - query).magnitude() <= radius {
+			found.push(order[mid]);
+		}
+		let offset = axis_value(query, depth) - axis_value(node, depth);
+		if offset <= radius {
+			self.within_radius_in(&order[..mid], depth + 1, query, radius, found);
+		}
+		if offset >= -radius {
+			self.within_radius_in(&order[mid + 1..], depth + 1, query, radius, found);
+		}
+	}
+}
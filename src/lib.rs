@@ -1,7 +1,48 @@
 //!BASIC VECTO	R2D IMPLEMETATION FOR USE WITHIN VARIOUS CODINGGAME PROJECTS, ETC TODO: DOCUMENT
 //!TODO: DOCUMENT THIS STUFF; also add more tests
+#![allow(clippy::tabs_in_doc_comments)]
+#[cfg(feature = "glam")] extern crate glam;
+#[macro_use]
+mod macros;
 pub mod vec2d;
 pub use self::vec2d::Vec2D;
+pub mod scalar;
+pub use self::scalar::Scalar;
+pub mod vecn;
+pub use self::vecn::VecN;
+pub mod shapes;
+pub mod raster;
+pub mod noise;
+pub mod steering;
+pub mod physics;
+pub mod polygon;
+pub mod rigid_body;
+pub mod camera;
+pub mod collision;
+pub mod quadtree;
+pub mod grid;
+pub mod pathfinding;
+pub mod hex;
+pub mod sdf;
+pub mod contour;
+#[cfg(feature = "glam")] pub mod interop;
+pub mod filter;
+pub mod integrate;
+pub mod geometry;
+pub mod sampling;
+pub mod polyline;
+pub mod coords;
+pub mod kdtree;
+pub mod encoding;
+pub mod anim;
+pub mod raycast;
+pub mod ballistics;
+pub mod joint;
+pub mod broadphase;
+pub mod curves;
+pub mod clip;
+pub mod field;
+pub mod cluster;
 #[cfg(test)]
 mod tests;
 
@@ -15,3 +56,18 @@ mod tests;
 pub fn sane_mod(a: f64, b: f64) -> f64 {
     a - (a/b).floor() * b
 }
+
+/// gets the shortest displacement from `from` to `to` in a world of `world_size` that wraps around on both axes (a torus)
+/// each component lies in `[-size/2, size/2)`
+/// #Examples
+/// ```
+/// 	use phys2d::toroidal_delta;
+/// 	use phys2d::vec2d::Vec2D;
+/// 	let size = Vec2D::new(100f64, 50f64);
+/// 	assert_eq!(toroidal_delta(Vec2D::new(95f64, 10f64), Vec2D::new(5f64, 20f64), size), Vec2D::new(10f64, 10f64));
+/// ```
+pub fn toroidal_delta(from: Vec2D<f64>, to: Vec2D<f64>, world_size: Vec2D<f64>) -> Vec2D<f64> {
+    let half = world_size / 2f64;
+    let direct = to - from;
+    Vec2D::new(sane_mod(direct.x + half.x, world_size.x) - half.x, sane_mod(direct.y + half.y, world_size.y) - half.y)
+}
@@ -0,0 +1,86 @@
+/// implements the component-wise operators (`+`, `-`, `+=`, `-=`, unary `-`) and the scalar ones (`*`, `/`, `*=`, `/=`)
+/// for a vector type with a single scalar parameter `T`, given the type, its impl generics and the docs of each operator.
+/// Note: the type must provide `map_components`, `zip_components`, `each_component_mut` and `zip_components_mut`,
+/// which the generated impls are written in terms of
+macro_rules! impl_vector_ops {
+	(
+		$vector:ty, [$($generics:tt)*],
+		$(#[$add:meta])* add,
+		$(#[$add_assign:meta])* add_assign,
+		$(#[$sub:meta])* sub,
+		$(#[$sub_assign:meta])* sub_assign,
+		$(#[$mul:meta])* mul,
+		$(#[$mul_assign:meta])* mul_assign,
+		$(#[$div:meta])* div,
+		$(#[$div_assign:meta])* div_assign,
+		$(#[$neg:meta])* neg
+	) => {
+		impl<$($generics)*> ::std::ops::Add<$vector> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::Add<Output=T> {
+			type Output = $vector;
+			$(#[$add])*
+			fn add(self, rhs: $vector) -> $vector {
+				self.zip_components(rhs, |a, b| a + b)
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::AddAssign<$vector> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::AddAssign {
+			$(#[$add_assign])*
+			fn add_assign(&mut self, rhs: $vector) {
+				self.zip_components_mut(rhs, |a, b| *a += b);
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::Sub<$vector> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::Sub<Output=T> {
+			type Output = $vector;
+			$(#[$sub])*
+			fn sub(self, rhs: $vector) -> $vector {
+				self.zip_components(rhs, |a, b| a - b)
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::SubAssign<$vector> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::SubAssign {
+			$(#[$sub_assign])*
+			fn sub_assign(&mut self, rhs: $vector) {
+				self.zip_components_mut(rhs, |a, b| *a -= b);
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::Mul<T> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::Mul<Output=T> {
+			type Output = $vector;
+			$(#[$mul])*
+			fn mul(self, rhs: T) -> $vector {
+				self.map_components(|a| a * rhs)
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::MulAssign<T> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::MulAssign {
+			$(#[$mul_assign])*
+			fn mul_assign(&mut self, rhs: T) {
+				self.each_component_mut(|a| *a *= rhs);
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::Div<T> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::Div<Output=T> {
+			type Output = $vector;
+			$(#[$div])*
+			fn div(self, rhs: T) -> $vector {
+				self.map_components(|a| a / rhs)
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::DivAssign<T> for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::DivAssign {
+			$(#[$div_assign])*
+			fn div_assign(&mut self, rhs: T) {
+				self.each_component_mut(|a| *a /= rhs);
+			}
+		}
+
+		impl<$($generics)*> ::std::ops::Neg for $vector where T: Copy+::std::fmt::Debug+PartialEq+PartialOrd+Default + ::std::ops::Neg<Output=T> {
+			type Output = $vector;
+			$(#[$neg])*
+			fn neg(self) -> $vector {
+				self.map_components(|a| -a)
+			}
+		}
+	};
+}
@@ -0,0 +1,129 @@
+use vec2d::Vec2D;
+
+/// hashes an integer lattice point together with a seed into a well-mixed 32 bit value
+pub(crate) fn lattice_hash(x: i32, y: i32, seed: u32) -> u32 {
+	let mut h = seed ^ 0x9E37_79B9;
+	h ^= (x as u32).wrapping_mul(0x85EB_CA6B);
+	h = h.rotate_left(13).wrapping_mul(0xC2B2_AE35);
+	h ^= (y as u32).wrapping_mul(0x27D4_EB2F);
+	h = h.rotate_left(17).wrapping_mul(0x1656_67B1);
+	h ^= h >> 16;
+	h = h.wrapping_mul(0x7FEB_352D);
+	h ^= h >> 15;
+	h = h.wrapping_mul(0x846C_A68B);
+	h ^ (h >> 16)
+}
+
+/// maps a lattice hash onto `[0,1]`
+pub(crate) fn lattice_value(x: i32, y: i32, seed: u32) -> f64 {
+	f64::from(lattice_hash(x, y, seed)) / f64::from(u32::MAX)
+}
+
+fn smoothstep(t: f64) -> f64 {
+	t * t * (3f64 - 2f64 * t)
+}
+
+/// Samples 2D value noise at `p`: deterministic pseudo-random values in `[0,1]` on the integer lattice, bilinearly interpolated (with smoothstep easing) in between.
+/// The same `p` and `seed` always produce the same value.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::noise::value_noise_2d;
+/// 	let p = Vec2D::new(3.25, -1.5);
+/// 	let n = value_noise_2d(p, 7);
+/// 	assert!((0f64..=1f64).contains(&n));
+/// 	assert_eq!(n, value_noise_2d(p, 7));
+/// ```
+pub fn value_noise_2d(p: Vec2D<f64>, seed: u32) -> f64 {
+	let (x0, y0) = (p.x.floor(), p.y.floor());
+	let (ix, iy) = (x0 as i32, y0 as i32);
+	let (tx, ty) = (smoothstep(p.x - x0), smoothstep(p.y - y0));
+
+	let top = lattice_value(ix, iy, seed) * (1f64 - tx) + lattice_value(ix.wrapping_add(1), iy, seed) * tx;
+	let bottom = lattice_value(ix, iy.wrapping_add(1), seed) * (1f64 - tx) + lattice_value(ix.wrapping_add(1), iy.wrapping_add(1), seed) * tx;
+	top * (1f64 - ty) + bottom * ty
+}
+
+/// Perlin's quintic fade curve, which has zero first and second derivatives at both ends
+fn fade(t: f64) -> f64 {
+	t * t * t * (t * (t * 6f64 - 15f64) + 10f64)
+}
+
+/// the dot product of the lattice point's pseudo-random unit gradient (one of eight evenly spaced directions) with `offset`
+fn gradient_dot(x: i32, y: i32, seed: u32, offset: Vec2D<f64>) -> f64 {
+	let direction = Vec2D::from_polar(1f64, f64::from(lattice_hash(x, y, seed) & 7) * std::f64::consts::FRAC_PI_4);
+	Vec2D::dot_product(direction, offset)
+}
+
+/// Samples 2D Perlin gradient noise at `p`: each integer lattice point has a pseudo-random gradient, and the noise blends
+/// their ramps with Perlin's quintic fade so it is smooth (with continuous first and second derivatives) everywhere.
+/// The result is zero on the lattice points and lies within `[-1,1]` (scaled so that extremes come close to it).
+/// The same `p` and `seed` always produce the same value.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::noise::perlin_2d;
+/// 	let n = perlin_2d(Vec2D::new(3.25, -1.5), 7);
+/// 	assert!((-1f64..=1f64).contains(&n));
+/// 	assert_eq!(perlin_2d(Vec2D::new(4f64, 2f64), 7), 0f64);
+/// ```
+pub fn perlin_2d(p: Vec2D<f64>, seed: u32) -> f64 {
+	let (x0, y0) = (p.x.floor(), p.y.floor());
+	let (ix, iy) = (x0 as i32, y0 as i32);
+	let (fx, fy) = (p.x - x0, p.y - y0);
+	let (tx, ty) = (fade(fx), fade(fy));
+	let (ix1, iy1) = (ix.wrapping_add(1), iy.wrapping_add(1));
+
+	let top = gradient_dot(ix, iy, seed, Vec2D::new(fx, fy)) * (1f64 - tx) + gradient_dot(ix1, iy, seed, Vec2D::new(fx - 1f64, fy)) * tx;
+	let bottom = gradient_dot(ix, iy1, seed, Vec2D::new(fx, fy - 1f64)) * (1f64 - tx) + gradient_dot(ix1, iy1, seed, Vec2D::new(fx - 1f64, fy - 1f64)) * tx;
+	// unit gradients peak at ±√½, so scale up towards ±1
+	((top * (1f64 - ty) + bottom * ty) * std::f64::consts::SQRT_2).clamp(-1f64, 1f64)
+}
+
+/// Layers `octaves` of the given `noise` into fractal Brownian motion: each octave samples the noise at `lacunarity` times
+/// the frequency of the last with `gain` times its amplitude, adding ever finer detail (typically a lacunarity of 2 and gain of ½).
+/// The sum is divided by the total amplitude, so the result stays within the range of `noise` itself.
+/// Zero octaves give zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::noise::{fbm, perlin_2d};
+/// 	let p = Vec2D::new(1.3, 2.7);
+/// 	let n = fbm(p, 5, 2f64, 0.5, |q| perlin_2d(q, 3));
+/// 	assert!((-1f64..=1f64).contains(&n));
+/// 	assert_eq!(fbm(p, 1, 2f64, 0.5, |q| perlin_2d(q, 3)), perlin_2d(p, 3));
+/// ```
+pub fn fbm(p: Vec2D<f64>, octaves: usize, lacunarity: f64, gain: f64, noise: impl Fn(Vec2D<f64>) -> f64) -> f64 {
+	let (mut sum, mut total_amplitude) = (0f64, 0f64);
+	let (mut frequency, mut amplitude) = (1f64, 1f64);
+	for _ in 0..octaves {
+		sum += noise(p * frequency) * amplitude;
+		total_amplitude += amplitude;
+		frequency *= lacunarity;
+		amplitude *= gain;
+	}
+	if total_amplitude == 0f64 { 0f64 } else { sum / total_amplitude }
+}
+
+/// where `domain_warp` samples its noise for the y displacement, far enough from the x samples that the two look unrelated
+const WARP_DECORRELATION_OFFSET: Vec2D<f64> = Vec2D{x: 5.2, y: 1.3};
+
+/// Warps the sample position `p` by the noise field itself, offsetting it by `warp_strength` times a vector whose components
+/// are the `noise` sampled at `p` and at a fixed offset from it. Sampling another pattern at the warped position gives it a
+/// marbled, flowing look. A zero `warp_strength` returns `p` unchanged.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::noise::{domain_warp, perlin_2d};
+/// 	let p = Vec2D::new(0.3, 0.6);
+/// 	let warped = domain_warp(p, |q| perlin_2d(q, 1), 4f64);
+/// 	let pattern = perlin_2d(warped, 2);
+/// 	assert!((-1f64..=1f64).contains(&pattern));
+/// 	assert_eq!(domain_warp(p, |q| perlin_2d(q, 1), 0f64), p);
+/// ```
+pub fn domain_warp(p: Vec2D<f64>, noise: impl Fn(Vec2D<f64>) -> f64, warp_strength: f64) -> Vec2D<f64> {
+	if warp_strength == 0f64 {
+		return p;
+	}
+	p + Vec2D::new(noise(p), noise(p + WARP_DECORRELATION_OFFSET)) * warp_strength
+}
@@ -0,0 +1,103 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use vec2d::Vec2D;
+use shapes::AABB;
+
+const ORTHOGONAL_STEPS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const DIAGONAL_STEPS: [(i32, i32); 4] = [(1, 1), (-1, 1), (-1, -1), (1, -1)];
+
+/// Finds a shortest path of grid cells from `start` to `goal` (both included) with A*, moving only between cells where `is_walkable` holds.
+/// Every step costs 1; with `diagonal` enabled diagonal steps are allowed (using the chebyshev heuristic, otherwise manhattan),
+/// but never to cut a corner past a blocked orthogonal neighbour.
+/// Returns `None` if the goal can't be reached.
+/// Note: `is_walkable` must be false outside some finite region, otherwise an unreachable goal is searched for forever.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::pathfinding::astar_grid;
+/// 	let in_bounds = |c: Vec2D<i32>| c.x >= 0 && c.x < 5 && c.y >= 0 && c.y < 5;
+/// 	let path = astar_grid(Vec2D::new(0, 0), Vec2D::new(3, 0), in_bounds, false).unwrap();
+/// 	assert_eq!(path, vec![Vec2D::new(0, 0), Vec2D::new(1, 0), Vec2D::new(2, 0), Vec2D::new(3, 0)]);
+/// ```
+pub fn astar_grid(start: Vec2D<i32>, goal: Vec2D<i32>, is_walkable: impl Fn(Vec2D<i32>) -> bool, diagonal: bool) -> Option<Vec<Vec2D<i32>>> {
+	if !is_walkable(goal) {
+		return None;
+	}
+	let heuristic = |cell: Vec2D<i32>| if diagonal { cell.chebyshev_distance(goal) } else { cell.manhattan_distance(goal) };
+
+	let mut open = BinaryHeap::new();
+	let mut came_from: HashMap<Vec2D<i32>, Vec2D<i32>> = HashMap::new();
+	let mut cost: HashMap<Vec2D<i32>, i32> = HashMap::new();
+	cost.insert(start, 0);
+	open.push(Reverse((heuristic(start), 0, start.x, start.y)));
+
+	while let Some(Reverse((_, g, x, y))) = open.pop() {
+		let current = Vec2D::new(x, y);
+		if current == goal {
+			let mut path = vec![current];
+			let mut cell = current;
+			while let Some(&previous) = came_from.get(&cell) {
+				path.push(previous);
+				cell = previous;
+			}
+			path.reverse();
+			return Some(path);
+		}
+		if g > cost[&current] {
+			continue;
+		}
+
+		let orthogonal = ORTHOGONAL_STEPS.iter().map(|&step| (step, true));
+		let diagonals = DIAGONAL_STEPS.iter().filter(|_| diagonal).map(|&(dx, dy)| {
+			let clear = is_walkable(Vec2D::new(x + dx, y)) && is_walkable(Vec2D::new(x, y + dy));
+			((dx, dy), clear)
+		});
+		for ((dx, dy), allowed) in orthogonal.chain(diagonals) {
+			let next = Vec2D::new(x + dx, y + dy);
+			if !allowed || !is_walkable(next) {
+				continue;
+			}
+			let next_cost = g + 1;
+			if cost.get(&next).map_or(true, |&known| next_cost < known) {
+				cost.insert(next, next_cost);
+				came_from.insert(next, current);
+				open.push(Reverse((next_cost + heuristic(next), next_cost, next.x, next.y)));
+			}
+		}
+	}
+	None
+}
+
+/// Builds a flow field (Dijkstra map) for many agents heading to the same destinations: for every cell within `bounds`
+/// (inclusive) that can reach one of the `goals` through walkable cells, gives the orthogonal unit step towards the nearest goal.
+/// Goal cells themselves map to the zero vector; unreachable and unwalkable cells are absent.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::pathfinding::flow_field;
+/// 	let field = flow_field(&[Vec2D::new(0, 0)], |_| true, AABB::new(Vec2D::new(0, 0), Vec2D::new(3, 0)));
+/// 	assert_eq!(field[&Vec2D::new(3, 0)], Vec2D::new(-1, 0));
+/// 	assert_eq!(field[&Vec2D::new(0, 0)], Vec2D::new(0, 0));
+/// ```
+pub fn flow_field(goals: &[Vec2D<i32>], is_walkable: impl Fn(Vec2D<i32>) -> bool, bounds: AABB<i32>) -> HashMap<Vec2D<i32>, Vec2D<i32>> {
+	let mut field = HashMap::new();
+	let mut frontier = VecDeque::new();
+	for &goal in goals {
+		if bounds.contains(goal) && is_walkable(goal) && !field.contains_key(&goal) {
+			field.insert(goal, Vec2D::new(0, 0));
+			frontier.push_back(goal);
+		}
+	}
+	// breadth-first from every goal at once, so each cell is first reached from its nearest goal
+	while let Some(cell) = frontier.pop_front() {
+		for &(dx, dy) in &ORTHOGONAL_STEPS {
+			let next = Vec2D::new(cell.x + dx, cell.y + dy);
+			if bounds.contains(next) && !field.contains_key(&next) && is_walkable(next) {
+				field.insert(next, Vec2D::new(-dx, -dy));
+				frontier.push_back(next);
+			}
+		}
+	}
+	field
+}
@@ -0,0 +1,217 @@
+use vec2d::Vec2D;
+
+/// Reflects `velocity` off a surface with the given `normal` (which need not be unit length), as for a perfectly elastic bounce.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::reflect_velocity;
+/// 	assert_eq!(reflect_velocity(Vec2D::new(3f64, -4f64), Vec2D::new(0f64, 2f64)), Vec2D::new(3f64, 4f64));
+/// ```
+pub fn reflect_velocity(velocity: Vec2D<f64>, normal: Vec2D<f64>) -> Vec2D<f64> {
+	let unit_normal = normal.to_unit();
+	velocity - unit_normal * (2f64 * Vec2D::dot_product(velocity, unit_normal))
+}
+
+/// Bounces `velocity` off a surface with the given `normal`: the normal component is reversed and scaled by `restitution`,
+/// while the tangential component is damped by `(1 - friction)`.
+/// With a restitution of 1 and no friction this is equivalent to `reflect_velocity`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::bounce;
+/// 	let v = bounce(Vec2D::new(2f64, -4f64), Vec2D::new(0f64, 1f64), 0.5, 0.5);
+/// 	assert_eq!(v, Vec2D::new(1f64, 2f64));
+/// ```
+pub fn bounce(velocity: Vec2D<f64>, normal: Vec2D<f64>, restitution: f64, friction: f64) -> Vec2D<f64> {
+	let unit_normal = normal.to_unit();
+	let normal_part = unit_normal * Vec2D::dot_product(velocity, unit_normal);
+	let tangent_part = velocity - normal_part;
+	tangent_part * (1f64 - friction) - normal_part * restitution
+}
+
+/// Resolves a perfectly elastic collision between two circles at `p1` and `p2` (with velocities `v1`, `v2` and masses `m1`, `m2`),
+/// returning their new velocities. Momentum and kinetic energy are conserved, and only the components along
+/// the line between the centres are exchanged; tangential components are left untouched.
+/// If the circles are already separating (or their centres coincide) the velocities are returned unchanged.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::resolve_elastic_collision;
+/// 	let (a, b) = resolve_elastic_collision(
+/// 		Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), 1f64,
+/// 		Vec2D::new(2f64, 0f64), Vec2D::new(-1f64, 0f64), 1f64);
+/// 	assert_eq!((a, b), (Vec2D::new(-1f64, 0f64), Vec2D::new(1f64, 0f64)));
+/// ```
+pub fn resolve_elastic_collision(p1: Vec2D<f64>, v1: Vec2D<f64>, m1: f64, p2: Vec2D<f64>, v2: Vec2D<f64>, m2: f64) -> (Vec2D<f64>, Vec2D<f64>) {
+	let offset = p2 - p1;
+	if offset == Vec2D::default() {
+		return (v1, v2);
+	}
+	let normal = offset.to_unit();
+	let approach = Vec2D::dot_product(v1 - v2, normal);
+	if approach <= 0f64 {
+		return (v1, v2);
+	}
+	let total_mass = m1 + m2;
+	(
+		v1 - normal * (2f64 * m2 / total_mass * approach),
+		v2 + normal * (2f64 * m1 / total_mass * approach),
+	)
+}
+
+/// Pushes two overlapping circles apart along the line between their centres so that they end up exactly touching,
+/// moving each by half of the overlap. Circles that do not overlap are returned unchanged.
+/// If the centres coincide exactly they are separated along the x axis.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::separate_circles;
+/// 	let (a, b) = separate_circles(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(1f64, 0f64), 1f64);
+/// 	assert_eq!((a, b), (Vec2D::new(-0.5, 0f64), Vec2D::new(1.5, 0f64)));
+/// ```
+pub fn separate_circles(p1: Vec2D<f64>, r1: f64, p2: Vec2D<f64>, r2: f64) -> (Vec2D<f64>, Vec2D<f64>) {
+	let offset = p2 - p1;
+	let distance = offset.magnitude();
+	let overlap = r1 + r2 - distance;
+	if overlap <= 0f64 {
+		return (p1, p2);
+	}
+	let normal = if distance == 0f64 { Vec2D::new(1f64, 0f64) } else { offset / distance };
+	let push = normal * (overlap / 2f64);
+	(p1 - push, p2 + push)
+}
+
+/// Hooke's law spring force acting on `a` from a spring connecting it to `b`.
+/// The force pulls `a` towards `b` when the spring is stretched beyond `rest_length` and pushes it away when compressed.
+/// Coincident points produce no force, as the direction is undefined.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::spring_force;
+/// 	let f = spring_force(Vec2D::new(0f64, 0f64), Vec2D::new(3f64, 0f64), 1f64, 2f64);
+/// 	assert_eq!(f, Vec2D::new(4f64, 0f64));
+/// ```
+pub fn spring_force(a: Vec2D<f64>, b: Vec2D<f64>, rest_length: f64, stiffness: f64) -> Vec2D<f64> {
+	let offset = b - a;
+	let length = offset.magnitude();
+	if length == 0f64 {
+		return Vec2D::default();
+	}
+	offset * (stiffness * (length - rest_length) / length)
+}
+
+/// Newtonian (inverse-square) gravitational force acting on `a` (mass `m1`) due to `b` (mass `m2`), with gravitational constant `g`.
+/// Coincident points produce no force rather than an infinite one.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::gravity_force;
+/// 	let f = gravity_force(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 2f64), 1f64, 2f64, 4f64);
+/// 	assert_eq!(f, Vec2D::new(0f64, 2f64));
+/// ```
+pub fn gravity_force(a: Vec2D<f64>, b: Vec2D<f64>, g: f64, m1: f64, m2: f64) -> Vec2D<f64> {
+	let offset = b - a;
+	let distance_squared = Vec2D::dot_product(offset, offset);
+	if distance_squared == 0f64 {
+		return Vec2D::default();
+	}
+	offset * (g * m1 * m2 / (distance_squared * distance_squared.sqrt()))
+}
+
+/// The signed angular velocity (radians per second, counter-clockwise positive) that turns `from_dir` to face `to_dir` over `dt` seconds,
+/// taking the shorter way around. The directions needn't be unit length; a zero direction or non-positive `dt` gives no rotation.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::angular_velocity;
+/// 	let w = angular_velocity(Vec2D::new(1f64, 0f64), Vec2D::new(0f64, -3f64), 0.5);
+/// 	assert_eq!(w, -std::f64::consts::PI);
+/// ```
+pub fn angular_velocity(from_dir: Vec2D<f64>, to_dir: Vec2D<f64>, dt: f64) -> f64 {
+	if dt <= 0f64 {
+		return 0f64;
+	}
+	Vec2D::cross_product(from_dir, to_dir).atan2(Vec2D::dot_product(from_dir, to_dir)) / dt
+}
+
+/// Applies linear drag for `dt` seconds: exponential decay of `velocity` at rate `linear_drag` per second, which is frame-rate independent.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::apply_drag;
+/// 	let v = apply_drag(Vec2D::new(10f64, 0f64), 2f64.ln(), 1f64);
+/// 	assert!((v - Vec2D::new(5f64, 0f64)).magnitude() < 1e-12);
+/// ```
+pub fn apply_drag(velocity: Vec2D<f64>, linear_drag: f64, dt: f64) -> Vec2D<f64> {
+	velocity * (-linear_drag * dt).exp()
+}
+
+/// Applies friction for `dt` seconds: a constant deceleration of `friction` against the direction of motion,
+/// stopping exactly at rest rather than reversing.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::apply_friction;
+/// 	assert_eq!(apply_friction(Vec2D::new(0f64, 5f64), 2f64, 1f64), Vec2D::new(0f64, 3f64));
+/// 	assert_eq!(apply_friction(Vec2D::new(0f64, 5f64), 2f64, 10f64), Vec2D::new(0f64, 0f64));
+/// ```
+pub fn apply_friction(velocity: Vec2D<f64>, friction: f64, dt: f64) -> Vec2D<f64> {
+	let speed = velocity.magnitude();
+	let reduction = friction * dt;
+	if speed <= reduction {
+		return Vec2D::default();
+	}
+	velocity * ((speed - reduction) / speed)
+}
+
+/// Applies an instantaneous `impulse` (such as a knockback) to a body of `mass` moving with `velocity`, returning its new velocity `velocity + impulse / mass`.
+/// A mass of zero is treated as infinite (an immovable body), leaving the velocity unchanged.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::physics::apply_impulse;
+/// 	assert_eq!(apply_impulse(Vec2D::new(1f64, 0f64), Vec2D::new(-6f64, 4f64), 2f64), Vec2D::new(-2f64, 2f64));
+/// ```
+pub fn apply_impulse(velocity: Vec2D<f64>, impulse: Vec2D<f64>, mass: f64) -> Vec2D<f64> {
+	if mass == 0f64 {
+		return velocity;
+	}
+	velocity + impulse / mass
+}
+
+/// a velocity as a heading and speed, for navigation-style code; converts to and from a velocity vector
+/// Note: the bearing is as for `Vec2D::bearing_degrees`, clockwise from north (the positive y axis)
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Velocity {
+	pub bearing_degrees: f64,
+	pub speed: f64
+}
+
+impl From<Vec2D<f64>> for Velocity {
+	/// Splits a velocity vector into its bearing and speed (a zero vector has a bearing of zero).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::physics::Velocity;
+	/// 	let velocity = Velocity::from(Vec2D::new(0f64, -3f64));
+	/// 	assert_eq!(velocity, Velocity{bearing_degrees: 180f64, speed: 3f64});
+	/// ```
+	fn from(v: Vec2D<f64>) -> Velocity {
+		Velocity{bearing_degrees: v.bearing_degrees(), speed: v.magnitude()}
+	}
+}
+
+impl From<Velocity> for Vec2D<f64> {
+	/// Builds the velocity vector heading along the bearing at the given speed.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::physics::Velocity;
+	/// 	let v: Vec2D<f64> = Velocity{bearing_degrees: 90f64, speed: 2f64}.into();
+	/// 	assert!((v - Vec2D::new(2f64, 0f64)).magnitude() < 1e-12);
+	/// ```
+	fn from(velocity: Velocity) -> Vec2D<f64> {
+		let (sin, cos) = velocity.bearing_degrees.to_radians().sin_cos();
+		Vec2D::new(sin, cos) * velocity.speed
+	}
+}
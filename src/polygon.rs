@@ -0,0 +1,574 @@
+use vec2d::Vec2D;
+use scalar::Scalar;
+use std::collections::HashMap;
+use shapes::Segment2D;
+use geometry::{circumcircle, delaunay};
+
+fn edges(polygon: &[Vec2D<f64>]) -> impl Iterator<Item=(Vec2D<f64>, Vec2D<f64>)> + '_ {
+	polygon.iter().enumerate().map(move |(i, &a)| (a, polygon[(i + 1) % polygon.len()]))
+}
+
+/// Tests whether `point` lies inside `polygon` using the even-odd (ray casting) rule.
+/// The polygon is given as its vertices in order; the closing edge back to the first vertex is implied.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::point_in_polygon;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert!(point_in_polygon(Vec2D::new(1f64, 1f64), &square));
+/// 	assert!(!point_in_polygon(Vec2D::new(3f64, 1f64), &square));
+/// ```
+pub fn point_in_polygon(point: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> bool {
+	let mut inside = false;
+	for (a, b) in edges(polygon) {
+		if (a.y > point.y) != (b.y > point.y) {
+			let crossing_x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+			if point.x < crossing_x {
+				inside = !inside;
+			}
+		}
+	}
+	inside
+}
+
+/// Computes the winding number of `polygon` around `point`: the net number of counter-clockwise turns the boundary makes about it.
+/// Zero means the point is outside; for simple polygons it is ±1 inside depending on the orientation of the vertices.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::winding_number;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert_eq!(winding_number(Vec2D::new(1f64, 1f64), &square), 1);
+/// 	assert_eq!(winding_number(Vec2D::new(5f64, 1f64), &square), 0);
+/// ```
+pub fn winding_number(point: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> i32 {
+	let mut winding = 0;
+	for (a, b) in edges(polygon) {
+		let side = Vec2D::cross_product(b - a, point - a);
+		if a.y <= point.y {
+			if b.y > point.y && side > 0f64 {
+				winding += 1;
+			}
+		} else if b.y <= point.y && side < 0f64 {
+			winding -= 1;
+		}
+	}
+	winding
+}
+
+/// Tests whether `point` lies inside `polygon` using the nonzero winding rule,
+/// which (unlike the even-odd rule) treats regions the boundary wraps around more than once as inside.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::point_in_polygon_winding;
+/// 	let triangle = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(0f64, 4f64)];
+/// 	assert!(point_in_polygon_winding(Vec2D::new(1f64, 1f64), &triangle));
+/// ```
+pub fn point_in_polygon_winding(point: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> bool {
+	winding_number(point, polygon) != 0
+}
+
+/// The average of a set of points (the zero vector if there are none).
+/// Note: for a filled polygon this is generally not its centre of area; see `polygon_centroid` for that.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::centroid;
+/// 	assert_eq!(centroid(&[Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 2f64)]), Vec2D::new(2f64, 1f64));
+/// ```
+pub fn centroid(points: &[Vec2D<f64>]) -> Vec2D<f64> {
+	if points.is_empty() {
+		return Vec2D::default();
+	}
+	let mut sum = Vec2D::default();
+	for &point in points {
+		sum += point;
+	}
+	sum / points.len() as f64
+}
+
+/// The mass-weighted average of a set of `(position, mass)` pairs (the zero vector if there are none, or their total mass is zero).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::center_of_mass;
+/// 	assert_eq!(center_of_mass(&[(Vec2D::new(0f64, 0f64), 1f64), (Vec2D::new(4f64, 0f64), 3f64)]), Vec2D::new(3f64, 0f64));
+/// ```
+pub fn center_of_mass(points: &[(Vec2D<f64>, f64)]) -> Vec2D<f64> {
+	let mut weighted = Vec2D::default();
+	let mut total = 0f64;
+	for &(point, mass) in points {
+		weighted += point * mass;
+		total += mass;
+	}
+	if total == 0f64 {
+		return Vec2D::default();
+	}
+	weighted / total
+}
+
+/// The signed area of a polygon (shoelace formula): positive when the vertices wind counter-clockwise, negative when clockwise.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::signed_area;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert_eq!(signed_area(&square), 4f64);
+/// ```
+pub fn signed_area(polygon: &[Vec2D<f64>]) -> f64 {
+	edges(polygon).map(|(a, b)| Vec2D::cross_product(a, b)).sum::<f64>() / 2f64
+}
+
+/// The area-weighted centroid (centre of area) of a filled simple polygon.
+/// Degenerate polygons with zero area fall back to the average of their vertices.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::polygon_centroid;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert_eq!(polygon_centroid(&square), Vec2D::new(1f64, 1f64));
+/// ```
+pub fn polygon_centroid(points: &[Vec2D<f64>]) -> Vec2D<f64> {
+	let area = signed_area(points);
+	if area == 0f64 {
+		return centroid(points);
+	}
+	let mut sum = Vec2D::default();
+	for (a, b) in edges(points) {
+		sum += (a + b) * Vec2D::cross_product(a, b);
+	}
+	sum / (6f64 * area)
+}
+
+/// The polar moment of inertia of a uniform-density polygon of the given `mass`, about its centroid.
+/// Zero-area polygons have no meaningful inertia and give zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::polygon_moment_of_inertia;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert!((polygon_moment_of_inertia(&square, 3f64) - 2f64).abs() < 1e-12);
+/// ```
+pub fn polygon_moment_of_inertia(points: &[Vec2D<f64>], mass: f64) -> f64 {
+	let centre = polygon_centroid(points);
+	let (mut numerator, mut denominator) = (0f64, 0f64);
+	for (a, b) in edges(points) {
+		let (a, b) = (a - centre, b - centre);
+		let cross = Vec2D::cross_product(a, b);
+		numerator += cross * (Vec2D::dot_product(a, a) + Vec2D::dot_product(a, b) + Vec2D::dot_product(b, b));
+		denominator += cross;
+	}
+	if denominator == 0f64 {
+		return 0f64;
+	}
+	mass * numerator / (6f64 * denominator)
+}
+
+/// Generates `segments + 1` evenly spaced points along the arc of the circle at `center` with `radius`,
+/// running from `start_angle` to `end_angle` (in radians); the first and last points lie exactly at those angles.
+/// The points run counter-clockwise when `end_angle > start_angle` and clockwise otherwise.
+/// With zero segments only the start point is produced.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::arc_points;
+/// 	let points = arc_points(Vec2D::new(0f64, 0f64), 2f64, 0f64, std::f64::consts::PI, 4);
+/// 	assert_eq!(points.len(), 5);
+/// 	assert_eq!(points[0], Vec2D::new(2f64, 0f64));
+/// 	let clockwise = arc_points(Vec2D::new(0f64, 0f64), 1f64, 0f64, -std::f64::consts::FRAC_PI_2, 2);
+/// 	assert!(clockwise[1].y < 0f64);
+/// 	assert!((clockwise[2] - Vec2D::new(0f64, -1f64)).magnitude() < 1e-12);
+/// ```
+pub fn arc_points(center: Vec2D<f64>, radius: f64, start_angle: f64, end_angle: f64, segments: usize) -> Vec<Vec2D<f64>> {
+	if segments == 0 {
+		return vec![center + Vec2D::new(start_angle.cos(), start_angle.sin()) * radius];
+	}
+	let step = (end_angle - start_angle) / segments as f64;
+	(0..segments + 1).map(|i| {
+		let angle = start_angle + step * i as f64;
+		center + Vec2D::new(angle.cos(), angle.sin()) * radius
+	}).collect()
+}
+
+/// Generates the vertices (counter-clockwise) of a regular polygon with `sides` sides inscribed in the circle at `center` with `radius`,
+/// with the first vertex at angle `rotation`.
+/// Fewer than 3 sides do not make a polygon, so an empty `Vec` is returned in that case.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::regular_polygon;
+/// 	assert_eq!(regular_polygon(Vec2D::new(0f64, 0f64), 1f64, 6, 0f64).len(), 6);
+/// 	assert!(regular_polygon(Vec2D::new(0f64, 0f64), 1f64, 2, 0f64).is_empty());
+/// ```
+pub fn regular_polygon(center: Vec2D<f64>, radius: f64, sides: usize, rotation: f64) -> Vec<Vec2D<f64>> {
+	if sides < 3 {
+		return Vec::new();
+	}
+	let mut points = arc_points(center, radius, rotation, rotation + 2f64 * std::f64::consts::PI, sides);
+	points.pop();
+	points
+}
+
+/// Orientation predicate for the turn `a -> b -> c`: positive when counter-clockwise, negative when clockwise and zero when collinear.
+/// (twice the signed area of the triangle `abc`)
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::orientation;
+/// 	assert!(orientation(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 1f64)) > 0f64);
+/// ```
+pub fn orientation(a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>) -> f64 {
+	Vec2D::cross_product(b - a, c - a)
+}
+
+/// The (unsigned) area of the triangle `abc`, `0.5 * |cross(b - a, c - a)|`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::triangle_area;
+/// 	assert_eq!(triangle_area(Vec2D::new(0, 0), Vec2D::new(4, 0), Vec2D::new(0, 3)), 6f64);
+/// ```
+pub fn triangle_area<T>(a: Vec2D<T>, b: Vec2D<T>, c: Vec2D<T>) -> f64 where T: Scalar {
+	0.5 * Vec2D::cross_product(b - a, c - a).to_f64().abs()
+}
+
+/// Tests whether `point` lies within (or on the boundary of) the triangle `abc`, in either winding order.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::point_in_triangle;
+/// 	let (a, b, c) = (Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(0f64, 4f64));
+/// 	assert!(point_in_triangle(Vec2D::new(1f64, 1f64), a, b, c));
+/// 	assert!(!point_in_triangle(Vec2D::new(3f64, 3f64), a, b, c));
+/// ```
+pub fn point_in_triangle(point: Vec2D<f64>, a: Vec2D<f64>, b: Vec2D<f64>, c: Vec2D<f64>) -> bool {
+	let (d1, d2, d3) = (orientation(a, b, point), orientation(b, c, point), orientation(c, a, point));
+	let has_negative = d1 < 0f64 || d2 < 0f64 || d3 < 0f64;
+	let has_positive = d1 > 0f64 || d2 > 0f64 || d3 > 0f64;
+	!(has_negative && has_positive)
+}
+
+/// Triangulates a simple (non self-intersecting) polygon by ear clipping, producing `n - 2` triangles for `n` vertices.
+/// Triangles are wound the same way as the input polygon. Degenerate input that has no ears left to clip stops early.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::triangulate;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	assert_eq!(triangulate(&square).len(), 2);
+/// ```
+pub fn triangulate(polygon: &[Vec2D<f64>]) -> Vec<[Vec2D<f64>; 3]> {
+	let mut triangles = Vec::new();
+	if polygon.len() < 3 {
+		return triangles;
+	}
+	let winding = if signed_area(polygon) < 0f64 { -1f64 } else { 1f64 };
+	let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+
+	while remaining.len() > 3 {
+		let count = remaining.len();
+		let ear = (0..count).find(|&i| {
+			let (prev, curr, next) = (polygon[remaining[(i + count - 1) % count]], polygon[remaining[i]], polygon[remaining[(i + 1) % count]]);
+			if orientation(prev, curr, next) * winding <= 0f64 {
+				return false;
+			}
+			remaining.iter().all(|&j| {
+				let p = polygon[j];
+				p == prev || p == curr || p == next || !point_in_triangle(p, prev, curr, next)
+			})
+		});
+		match ear {
+			Some(i) => {
+				triangles.push([polygon[remaining[(i + count - 1) % count]], polygon[remaining[i]], polygon[remaining[(i + 1) % count]]]);
+				remaining.remove(i);
+			},
+			None => return triangles
+		}
+	}
+	triangles.push([polygon[remaining[0]], polygon[remaining[1]], polygon[remaining[2]]]);
+	triangles
+}
+
+/// Offsets a simple polygon by moving every edge `distance` along its outward normal (inward for a negative distance),
+/// placing each vertex where its two neighbouring offset edges meet. Works with either winding order.
+/// Corners are mitered without a limit, so very sharp corners extend far beyond the offset distance;
+/// and insetting by more than half the polygon's thickness makes edges cross over, giving a self-intersecting result.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::offset_polygon;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let grown = offset_polygon(&square, 1f64);
+/// 	assert_eq!(grown[0], Vec2D::new(-1f64, -1f64));
+/// 	assert_eq!(grown[2], Vec2D::new(3f64, 3f64));
+/// ```
+pub fn offset_polygon(points: &[Vec2D<f64>], distance: f64) -> Vec<Vec2D<f64>> {
+	let count = points.len();
+	if count < 3 {
+		return points.to_vec();
+	}
+	// for counter-clockwise polygons the outward normal is on the right of each edge
+	let winding = if signed_area(points) < 0f64 { -1f64 } else { 1f64 };
+	let normals: Vec<Vec2D<f64>> = edges(points).map(|(a, b)| -(b - a).perp().to_unit() * winding).collect();
+	(0..count).map(|i| {
+		let (before, after) = (normals[(i + count - 1) % count], normals[i]);
+		let denominator = 1f64 + Vec2D::dot_product(before, after);
+		if denominator.abs() < 1e-12 {
+			// the edges double back on each other, so there is no meeting point
+			points[i] + after * distance
+		} else {
+			points[i] + (before + after) * (distance / denominator)
+		}
+	}).collect()
+}
+
+/// Computes the convex hull of a point set with Andrew's monotone chain algorithm,
+/// returned counter-clockwise starting from the lowest-leftmost point, without collinear points along its edges.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::convex_hull;
+/// 	let points = [Vec2D::new(1f64, 1f64), Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 3f64)];
+/// 	assert_eq!(convex_hull(&points), vec![Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(1f64, 3f64)]);
+/// ```
+pub fn convex_hull(points: &[Vec2D<f64>]) -> Vec<Vec2D<f64>> {
+	let mut sorted = points.to_vec();
+	sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+	sorted.dedup();
+	if sorted.len() < 3 {
+		return sorted;
+	}
+	let mut hull: Vec<Vec2D<f64>> = Vec::with_capacity(sorted.len() + 1);
+	// the lower chain left to right, then the upper chain back again
+	for pass in 0..2 {
+		let chain_start = hull.len();
+		for i in 0..sorted.len() {
+			let p = if pass == 0 { sorted[i] } else { sorted[sorted.len() - 1 - i] };
+			while hull.len() >= chain_start + 2 && orientation(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0f64 {
+				hull.pop();
+			}
+			hull.push(p);
+		}
+		// the last point of each chain starts the other
+		hull.pop();
+	}
+	hull
+}
+
+/// the polygon's vertices in counter-clockwise order (reversed if they were clockwise)
+pub(crate) fn counter_clockwise(polygon: &[Vec2D<f64>]) -> Vec<Vec2D<f64>> {
+	let mut points = polygon.to_vec();
+	if signed_area(&points) < 0f64 {
+		points.reverse();
+	}
+	points
+}
+
+/// Walks the edges of a counter-clockwise convex polygon, pairing each edge `i` with the vertex furthest from it (rotating calipers).
+fn antipodal_pairs(hull: &[Vec2D<f64>]) -> Vec<(usize, usize)> {
+	let n = hull.len();
+	let mut pairs = Vec::with_capacity(n);
+	let mut j = 1;
+	for i in 0..n {
+		let (a, b) = (hull[i], hull[(i + 1) % n]);
+		while orientation(a, b, hull[(j + 1) % n]) > orientation(a, b, hull[j]) {
+			j = (j + 1) % n;
+		}
+		pairs.push((i, j));
+	}
+	pairs
+}
+
+/// The diameter of a convex polygon (the greatest distance between two of its vertices), found with rotating calipers in `O(n)`.
+/// The hull may be in either winding order.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::polygon_diameter;
+/// 	let rect = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 3f64), Vec2D::new(0f64, 3f64)];
+/// 	assert_eq!(polygon_diameter(&rect), 5f64);
+/// ```
+pub fn polygon_diameter(hull: &[Vec2D<f64>]) -> f64 {
+	match hull.len() {
+		0 | 1 => 0f64,
+		2 => (hull[1] - hull[0]).magnitude(),
+		n => {
+			let hull = counter_clockwise(hull);
+			antipodal_pairs(&hull).into_iter()
+				.map(|(i, j)| (hull[i] - hull[j]).magnitude().max((hull[(i + 1) % n] - hull[j]).magnitude()))
+				.fold(0f64, f64::max)
+		}
+	}
+}
+
+/// The minimum width of a convex polygon (the narrowest gap between two parallel lines enclosing it), found with rotating calipers in `O(n)`.
+/// The hull may be in either winding order; fewer than three vertices have no width.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::polygon_min_width;
+/// 	let rect = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 3f64), Vec2D::new(0f64, 3f64)];
+/// 	assert_eq!(polygon_min_width(&rect), 3f64);
+/// ```
+pub fn polygon_min_width(hull: &[Vec2D<f64>]) -> f64 {
+	if hull.len() < 3 {
+		return 0f64;
+	}
+	let hull = counter_clockwise(hull);
+	let n = hull.len();
+	antipodal_pairs(&hull).into_iter()
+		.map(|(i, j)| {
+			let (a, b) = (hull[i], hull[(i + 1) % n]);
+			orientation(a, b, hull[j]) / (b - a).magnitude()
+		})
+		.fold(f64::INFINITY, f64::min)
+}
+
+/// Finds the minimum-area oriented bounding rectangle of a point set, returned as `(center, half_extents, rotation)`:
+/// the rectangle spans `half_extents` either side of `center` along its own axes, which are rotated counter-clockwise by `rotation` radians in `[0, π/2)`.
+/// One side of the optimal rectangle always lies along an edge of the convex hull, so each hull edge is tried in turn.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::min_area_rect;
+/// 	let diamond = [Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64), Vec2D::new(-1f64, 0f64), Vec2D::new(0f64, -1f64)];
+/// 	let (center, half_extents, rotation) = min_area_rect(&diamond);
+/// 	assert!(center.magnitude() < 1e-12);
+/// 	assert!((half_extents - Vec2D::new(0.5f64.sqrt(), 0.5f64.sqrt())).magnitude() < 1e-12);
+/// 	assert!((rotation - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+/// ```
+pub fn min_area_rect(points: &[Vec2D<f64>]) -> (Vec2D<f64>, Vec2D<f64>, f64) {
+	let hull = convex_hull(points);
+	match hull.len() {
+		0 => return (Vec2D::default(), Vec2D::default(), 0f64),
+		1 => return (hull[0], Vec2D::default(), 0f64),
+		_ => {}
+	}
+	let mut best: Option<(f64, Vec2D<f64>, Vec2D<f64>, f64)> = None;
+	for i in 0..hull.len() {
+		let edge = hull[(i + 1) % hull.len()] - hull[i];
+		// pick whichever of the edge and its perpendicular has an angle in [0, π/2) as the rectangle's first axis
+		let mut axis = edge.to_unit();
+		while axis.x <= 0f64 || axis.y < 0f64 {
+			axis = axis.perp();
+		}
+		let normal = axis.perp();
+		let (mut min, mut max) = (Vec2D::new(f64::INFINITY, f64::INFINITY), Vec2D::new(f64::NEG_INFINITY, f64::NEG_INFINITY));
+		for &p in &hull {
+			let local = Vec2D::new(Vec2D::dot_product(p, axis), Vec2D::dot_product(p, normal));
+			min = Vec2D::new(min.x.min(local.x), min.y.min(local.y));
+			max = Vec2D::new(max.x.max(local.x), max.y.max(local.y));
+		}
+		let area = (max.x - min.x) * (max.y - min.y);
+		if best.map_or(true, |(best_area, _, _, _)| area < best_area) {
+			let local_center = (min + max) / 2f64;
+			let center = axis * local_center.x + normal * local_center.y;
+			best = Some((area, center, (max - min) / 2f64, axis.angle()));
+		}
+	}
+	let (_, center, half_extents, rotation) = best.unwrap();
+	(center, half_extents, rotation)
+}
+
+/// Finds the principal axes of a point set: the unit eigenvectors of its 2x2 covariance matrix, returned as `(major, minor)`.
+/// The major axis is the direction the points spread out the most along, and the minor axis is perpendicular to it (counter-clockwise).
+/// Each axis could equally point the opposite way; here the major axis always has an angle in `(-π/2, π/2]`.
+/// When there is no dominant direction (e.g. fewer than two distinct points) the axes are those of the coordinate system.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::principal_axis;
+/// 	let (major, minor) = principal_axis(&[Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 5f64), Vec2D::new(0.5, 2f64)]);
+/// 	assert!(major.y.abs() > 0.99);
+/// 	assert!(Vec2D::dot_product(major, minor).abs() < 1e-12);
+/// ```
+pub fn principal_axis(points: &[Vec2D<f64>]) -> (Vec2D<f64>, Vec2D<f64>) {
+	let mean = centroid(points);
+	let (mut xx, mut xy, mut yy) = (0f64, 0f64, 0f64);
+	for &p in points {
+		let d = p - mean;
+		xx += d.x * d.x;
+		xy += d.x * d.y;
+		yy += d.y * d.y;
+	}
+	// the angle of the larger eigenvector of [[xx, xy], [xy, yy]]
+	let angle = 0.5 * (2f64 * xy).atan2(xx - yy);
+	let major = Vec2D::new(angle.cos(), angle.sin());
+	(major, major.perp())
+}
+
+/// Finds the nearest point to `point` on the boundary of `polygon` (its edges, including the closing one),
+/// so points inside the polygon are also moved out to the closest edge. An empty polygon gives `point` back unchanged.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::closest_point_on_polygon;
+/// 	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 4f64), Vec2D::new(0f64, 4f64)];
+/// 	assert_eq!(closest_point_on_polygon(Vec2D::new(6f64, 1f64), &square), Vec2D::new(4f64, 1f64));
+/// ```
+pub fn closest_point_on_polygon(point: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> Vec2D<f64> {
+	edges(polygon)
+		.map(|(a, b)| Segment2D::new(a, b).closest_point(point))
+		.min_by(|p, q| (*p - point).magnitude().total_cmp(&(*q - point).magnitude()))
+		.unwrap_or(point)
+}
+
+/// Approximates the medial axis of `polygon` (the centre-lines equidistant from two or more sides, as for a road or river's
+/// course) from the Voronoi diagram of `samples` points spaced evenly around its boundary: the Voronoi edges between samples that
+/// aren't neighbours along the boundary, wherever they lie inside the polygon, trace the axis. These are found as the duals
+/// of the samples' `delaunay` triangulation, joining the circumcentres of each pair of triangles sharing an edge. More samples give a
+/// finer axis; its branches reach into each convex corner.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polygon::medial_axis_approx;
+/// 	let strip = [Vec2D::new(0f64, 0f64), Vec2D::new(8f64, 0f64), Vec2D::new(8f64, 2f64), Vec2D::new(0f64, 2f64)];
+/// 	let axis = medial_axis_approx(&strip, 64);
+/// 	// away from the ends, the axis runs down the middle
+/// 	let middle = axis.iter().filter(|s| s.start.x > 2f64 && s.start.x < 6f64);
+/// 	assert!(middle.clone().count() > 0);
+/// 	assert!(middle.into_iter().all(|s| (s.start.y - 1f64).abs() < 0.1));
+/// ```
+pub fn medial_axis_approx(polygon: &[Vec2D<f64>], samples: usize) -> Vec<Segment2D<f64>> {
+	let perimeter: f64 = edges(polygon).map(|(a, b)| (b - a).magnitude()).sum();
+	if polygon.len() < 3 || perimeter == 0f64 {
+		return Vec::new();
+	}
+	let samples = samples.max(3);
+	let spacing = perimeter / samples as f64;
+	let mut points = Vec::with_capacity(samples);
+	let mut travelled = 0f64;
+	for (a, b) in edges(polygon) {
+		let length = (b - a).magnitude();
+		while points.len() < samples && (points.len() as f64) * spacing < travelled + length {
+			points.push(a.lerp(b, ((points.len() as f64) * spacing - travelled) / length));
+		}
+		travelled += length;
+	}
+
+	let triangles = delaunay(&points);
+	let mut sharing: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+	for (index, t) in triangles.iter().enumerate() {
+		for &(a, b) in [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])].iter() {
+			sharing.entry((a.min(b), a.max(b))).or_default().push(index);
+		}
+	}
+	let centers: Vec<Option<Vec2D<f64>>> = triangles.iter().map(|t| circumcircle(points[t[0]], points[t[1]], points[t[2]]).map(|c| c.center)).collect();
+
+	let mut edge_list: Vec<_> = sharing.into_iter().collect();
+	edge_list.sort_unstable_by_key(|&(edge, _)| edge);
+	let mut axis = Vec::new();
+	for ((a, b), shared_by) in edge_list {
+		let neighbouring = b - a == 1 || (a == 0 && b == points.len() - 1);
+		if neighbouring || shared_by.len() != 2 {
+			continue;
+		}
+		if let (Some(start), Some(end)) = (centers[shared_by[0]], centers[shared_by[1]]) {
+			if start != end && point_in_polygon(start, polygon) && point_in_polygon(end, polygon) {
+				axis.push(Segment2D::new(start, end));
+			}
+		}
+	}
+	axis
+}
@@ -0,0 +1,96 @@
+use vec2d::Vec2D;
+use shapes::Segment2D;
+
+/// the distance from `point` to the segment from `start` to `end`
+fn distance_to_segment(point: Vec2D<f64>, start: Vec2D<f64>, end: Vec2D<f64>) -> f64 {
+	(point - Segment2D::new(start, end).closest_point(point)).magnitude()
+}
+
+fn simplify_range(points: &[Vec2D<f64>], epsilon: f64, keep: &mut [bool]) {
+	if points.len() < 3 {
+		return;
+	}
+	let (first, last) = (points[0], points[points.len() - 1]);
+	let (furthest, distance) = points[1..points.len() - 1].iter().enumerate()
+		.map(|(i, &p)| (i + 1, distance_to_segment(p, first, last)))
+		.fold((0, -1f64), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+	if distance > epsilon {
+		keep[furthest] = true;
+		simplify_range(&points[..furthest + 1], epsilon, &mut keep[..furthest + 1]);
+		simplify_range(&points[furthest..], epsilon, &mut keep[furthest..]);
+	}
+}
+
+/// Simplifies a polyline with the Ramer-Douglas-Peucker algorithm, dropping vertices that lie within `epsilon` of the simplified line.
+/// The endpoints are always kept.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polyline::simplify_polyline;
+/// 	let line = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0.1), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 3f64)];
+/// 	assert_eq!(simplify_polyline(&line, 0.5), vec![Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 3f64)]);
+/// ```
+pub fn simplify_polyline(points: &[Vec2D<f64>], epsilon: f64) -> Vec<Vec2D<f64>> {
+	if points.len() < 3 {
+		return points.to_vec();
+	}
+	let mut keep = vec![false; points.len()];
+	keep[0] = true;
+	keep[points.len() - 1] = true;
+	simplify_range(points, epsilon, &mut keep);
+	points.iter().zip(keep).filter(|&(_, kept)| kept).map(|(&p, _)| p).collect()
+}
+
+/// Smooths a polyline with `iterations` rounds of Chaikin's corner cutting, replacing each edge with points a quarter and three quarters along it.
+/// Open polylines keep their endpoints; `closed` polylines also cut the edge from the last point back to the first.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polyline::chaikin_smooth;
+/// 	let line = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 4f64)];
+/// 	assert_eq!(chaikin_smooth(&line, 1, false), vec![
+/// 		Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(3f64, 0f64),
+/// 		Vec2D::new(4f64, 1f64), Vec2D::new(4f64, 3f64), Vec2D::new(4f64, 4f64)
+/// 	]);
+/// ```
+pub fn chaikin_smooth(points: &[Vec2D<f64>], iterations: usize, closed: bool) -> Vec<Vec2D<f64>> {
+	let mut current = points.to_vec();
+	for _ in 0..iterations {
+		if current.len() < 3 {
+			break;
+		}
+		let edge_count = if closed { current.len() } else { current.len() - 1 };
+		let mut next = Vec::with_capacity(edge_count * 2 + 2);
+		if !closed {
+			next.push(current[0]);
+		}
+		for i in 0..edge_count {
+			let (a, b) = (current[i], current[(i + 1) % current.len()]);
+			next.push(a.lerp(b, 0.25));
+			next.push(a.lerp(b, 0.75));
+		}
+		if !closed {
+			next.push(current[current.len() - 1]);
+		}
+		current = next;
+	}
+	current
+}
+
+/// Sums the signed turning angles (in radians, counter-clockwise positive) between consecutive segments of a path.
+/// A path that ends where it starts is treated as closed, so the turn from its last segment back into its first is included too,
+/// and a simple closed polygon then totals `2π` counter-clockwise or `-2π` clockwise. Zero-length segments are skipped.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::polyline::total_turning;
+/// 	let path = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 1f64)];
+/// 	assert_eq!(total_turning(&path), std::f64::consts::FRAC_PI_2);
+/// ```
+pub fn total_turning(points: &[Vec2D<f64>]) -> f64 {
+	let mut directions: Vec<Vec2D<f64>> = points.windows(2).map(|pair| pair[1] - pair[0]).filter(|&d| d != Vec2D::default()).collect();
+	if points.len() > 2 && points[0] == points[points.len() - 1] && !directions.is_empty() {
+		directions.push(directions[0]);
+	}
+	directions.windows(2).map(|pair| Vec2D::cross_product(pair[0], pair[1]).atan2(Vec2D::dot_product(pair[0], pair[1]))).sum()
+}
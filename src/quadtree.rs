@@ -0,0 +1,132 @@
+use vec2d::Vec2D;
+use shapes::AABB;
+
+/// beyond this depth nodes stop subdividing, so that many items at (nearly) the same position can't recurse forever
+const MAX_DEPTH: usize = 16;
+
+/// a point quadtree storing values at `Vec2D<f64>` positions within `bounds`, for fast region queries
+/// Note: each node holds up to `capacity` items before splitting into four equal quadrants
+#[derive(Clone, Debug)]
+pub struct QuadTree<V> {
+	pub bounds: AABB<f64>,
+	capacity: usize,
+	depth: usize,
+	items: Vec<(Vec2D<f64>, V)>,
+	children: Option<Box<[QuadTree<V>; 4]>>
+}
+
+impl<V> QuadTree<V> {
+	/// Creates an empty tree covering `bounds`, splitting nodes once they exceed `capacity` items (at least one).
+	pub fn new(bounds: AABB<f64>, capacity: usize) -> QuadTree<V> {
+		QuadTree::with_depth(bounds, capacity.max(1), 0)
+	}
+
+	fn with_depth(bounds: AABB<f64>, capacity: usize, depth: usize) -> QuadTree<V> {
+		QuadTree{bounds, capacity, depth, items: Vec::new(), children: None}
+	}
+
+	fn centre(&self) -> Vec2D<f64> {
+		(self.bounds.min + self.bounds.max) / 2f64
+	}
+
+	fn quadrant(&self, position: Vec2D<f64>) -> usize {
+		let centre = self.centre();
+		match (position.x >= centre.x, position.y >= centre.y) {
+			(false, false) => 0,
+			(true, false) => 1,
+			(false, true) => 2,
+			(true, true) => 3
+		}
+	}
+
+	fn subdivide(&mut self) {
+		let (min, max, centre) = (self.bounds.min, self.bounds.max, self.centre());
+		let (capacity, depth) = (self.capacity, self.depth + 1);
+		let children = [
+			QuadTree::with_depth(AABB::new(min, centre), capacity, depth),
+			QuadTree::with_depth(AABB::new(Vec2D::new(centre.x, min.y), Vec2D::new(max.x, centre.y)), capacity, depth),
+			QuadTree::with_depth(AABB::new(Vec2D::new(min.x, centre.y), Vec2D::new(centre.x, max.y)), capacity, depth),
+			QuadTree::with_depth(AABB::new(centre, max), capacity, depth),
+		];
+		self.children = Some(Box::new(children));
+		for (position, value) in std::mem::take(&mut self.items) {
+			self.insert_unchecked(position, value);
+		}
+	}
+
+	fn insert_unchecked(&mut self, position: Vec2D<f64>, value: V) {
+		let quadrant = self.quadrant(position);
+		match self.children {
+			Some(ref mut children) => children[quadrant].insert_unchecked(position, value),
+			None => {
+				self.items.push((position, value));
+				if self.items.len() > self.capacity && self.depth < MAX_DEPTH {
+					self.subdivide();
+				}
+			}
+		}
+	}
+
+	/// Inserts `value` at `position`, returning `false` (and discarding it) if the position lies outside the tree's bounds.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	use phys2d::quadtree::QuadTree;
+	/// 	let mut tree = QuadTree::new(AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 10f64)), 4);
+	/// 	assert!(tree.insert(Vec2D::new(1f64, 2f64), "a"));
+	/// 	assert!(!tree.insert(Vec2D::new(11f64, 2f64), "b"));
+	/// 	assert_eq!(tree.len(), 1);
+	/// ```
+	pub fn insert(&mut self, position: Vec2D<f64>, value: V) -> bool {
+		if !self.bounds.contains(position) {
+			return false;
+		}
+		self.insert_unchecked(position, value);
+		true
+	}
+
+	/// The number of items stored in the tree.
+	pub fn len(&self) -> usize {
+		match self.children {
+			Some(ref children) => children.iter().map(|child| child.len()).sum(),
+			None => self.items.len()
+		}
+	}
+
+	/// Whether the tree holds no items.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Collects every value whose position lies within `region` (boundary included).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	use phys2d::quadtree::QuadTree;
+	/// 	let mut tree = QuadTree::new(AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 10f64)), 1);
+	/// 	tree.insert(Vec2D::new(1f64, 1f64), 1);
+	/// 	tree.insert(Vec2D::new(9f64, 9f64), 2);
+	/// 	assert_eq!(tree.query(&AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(5f64, 5f64))), vec![&1]);
+	/// ```
+	pub fn query(&self, region: &AABB<f64>) -> Vec<&V> {
+		let mut found = Vec::new();
+		self.query_into(region, &mut found);
+		found
+	}
+
+	fn query_into<'a>(&'a self, region: &AABB<f64>, found: &mut Vec<&'a V>) {
+		if !self.bounds.intersects(region) {
+			return;
+		}
+		match self.children {
+			Some(ref children) => {
+				for child in children.iter() {
+					child.query_into(region, found);
+				}
+			},
+			None => found.extend(self.items.iter().filter(|item| region.contains(item.0)).map(|item| &item.1))
+		}
+	}
+}
@@ -0,0 +1,244 @@
+use vec2d::Vec2D;
+use shapes::AABB;
+
+fn fpart(x: f64) -> f64 {
+	x - x.floor()
+}
+
+fn rfpart(x: f64) -> f64 {
+	1f64 - fpart(x)
+}
+
+/// Rasterizes the line from `start` to `end` using Xiaolin Wu's anti-aliased line algorithm.
+/// Each pixel touched by the line is returned along with its coverage in `[0,1]`; pixels with zero coverage are omitted.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raster::wu_line;
+/// 	let pixels = wu_line(Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64));
+/// 	assert!(pixels.iter().any(|&(p, _)| p == Vec2D::new(0, 0)));
+/// 	assert!(pixels.iter().any(|&(p, _)| p == Vec2D::new(4, 0)));
+/// ```
+pub fn wu_line(start: Vec2D<f64>, end: Vec2D<f64>) -> Vec<(Vec2D<i32>, f64)> {
+	let mut pixels = Vec::new();
+	let steep = (end.y - start.y).abs() > (end.x - start.x).abs();
+	let (mut a, mut b) = if steep {
+		(Vec2D::new(start.y, start.x), Vec2D::new(end.y, end.x))
+	} else {
+		(start, end)
+	};
+	if a.x > b.x {
+		std::mem::swap(&mut a, &mut b);
+	}
+
+	{
+		let mut plot = |x: f64, y: f64, coverage: f64| {
+			if coverage > 0f64 {
+				let pixel = if steep { Vec2D::new(y as i32, x as i32) } else { Vec2D::new(x as i32, y as i32) };
+				pixels.push((pixel, coverage.min(1f64)));
+			}
+		};
+
+		let delta = b - a;
+		let gradient = if delta.x == 0f64 { 1f64 } else { delta.y / delta.x };
+
+		let x_end = (a.x + 0.5).floor();
+		let y_end = a.y + gradient * (x_end - a.x);
+		let x_gap = rfpart(a.x + 0.5);
+		let x_first = x_end;
+		plot(x_first, y_end.floor(), rfpart(y_end) * x_gap);
+		plot(x_first, y_end.floor() + 1f64, fpart(y_end) * x_gap);
+		let mut inter_y = y_end + gradient;
+
+		let x_end = (b.x + 0.5).floor();
+		let y_end = b.y + gradient * (x_end - b.x);
+		let x_gap = fpart(b.x + 0.5);
+		let x_last = x_end;
+		if x_last != x_first {
+			plot(x_last, y_end.floor(), rfpart(y_end) * x_gap);
+			plot(x_last, y_end.floor() + 1f64, fpart(y_end) * x_gap);
+		}
+
+		let mut x = x_first + 1f64;
+		while x < x_last {
+			plot(x, inter_y.floor(), rfpart(inter_y));
+			plot(x, inter_y.floor() + 1f64, fpart(inter_y));
+			inter_y += gradient;
+			x += 1f64;
+		}
+	}
+	pixels
+}
+
+/// an iterator over the integer cells of a line, as produced by Bresenham's algorithm (see `bresenham_line`)
+#[derive(Copy, Clone, Debug)]
+pub struct BresenhamLine {
+	current: Vec2D<i32>,
+	end: Vec2D<i32>,
+	delta: Vec2D<i32>,
+	step: Vec2D<i32>,
+	error: i32,
+	done: bool
+}
+
+impl Iterator for BresenhamLine {
+	type Item = Vec2D<i32>;
+	fn next(&mut self) -> Option<Vec2D<i32>> {
+		if self.done {
+			return None;
+		}
+		let cell = self.current;
+		if cell == self.end {
+			self.done = true;
+			return Some(cell);
+		}
+		let doubled = 2 * self.error;
+		if doubled >= self.delta.y {
+			self.error += self.delta.y;
+			self.current.x += self.step.x;
+		}
+		if doubled <= self.delta.x {
+			self.error += self.delta.x;
+			self.current.y += self.step.y;
+		}
+		Some(cell)
+	}
+}
+
+/// Iterates over the cells on the line from `start` to `end` (both included) using Bresenham's line algorithm,
+/// stepping one cell at a time so consecutive cells are 8-connected.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raster::bresenham_line;
+/// 	let cells: Vec<_> = bresenham_line(Vec2D::new(0, 0), Vec2D::new(3, 1)).collect();
+/// 	assert_eq!(cells, vec![Vec2D::new(0, 0), Vec2D::new(1, 0), Vec2D::new(2, 1), Vec2D::new(3, 1)]);
+/// ```
+pub fn bresenham_line(start: Vec2D<i32>, end: Vec2D<i32>) -> BresenhamLine {
+	let delta = Vec2D::new((end.x - start.x).abs(), -(end.y - start.y).abs());
+	let step = Vec2D::new(if start.x < end.x { 1 } else { -1 }, if start.y < end.y { 1 } else { -1 });
+	BresenhamLine{current: start, end, delta, step, error: delta.x + delta.y, done: false}
+}
+
+/// the grid value at a cell, with the coordinates clamped onto the grid
+fn grid_value(grid: &[f64], width: usize, height: usize, x: i64, y: i64) -> f64 {
+	let x = x.clamp(0, width as i64 - 1) as usize;
+	let y = y.clamp(0, height as i64 - 1) as usize;
+	grid[y * width + x]
+}
+
+/// Samples the `width` by `height` row-major `grid` of values at the fractional position `p` (with cell `(x, y)` at
+/// `grid[y * width + x]`), blending the four surrounding cells bilinearly. Positions beyond the grid are clamped to its edge,
+/// and an empty grid samples as zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raster::bilinear_sample;
+/// 	let grid = [0f64, 1f64, 2f64, 3f64];
+/// 	assert_eq!(bilinear_sample(&grid, 2, 2, Vec2D::new(0.5, 0f64)), 0.5);
+/// 	assert_eq!(bilinear_sample(&grid, 2, 2, Vec2D::new(5f64, 5f64)), 3f64);
+/// ```
+pub fn bilinear_sample(grid: &[f64], width: usize, height: usize, p: Vec2D<f64>) -> f64 {
+	if width == 0 || height == 0 {
+		return 0f64;
+	}
+	let x = p.x.clamp(0f64, (width - 1) as f64);
+	let y = p.y.clamp(0f64, (height - 1) as f64);
+	let (x0, y0) = (x.floor(), y.floor());
+	let (tx, ty) = (x - x0, y - y0);
+	let (ix, iy) = (x0 as i64, y0 as i64);
+	let value = |dx: i64, dy: i64| grid_value(grid, width, height, ix + dx, iy + dy);
+	let top = value(0, 0) * (1f64 - tx) + value(1, 0) * tx;
+	let bottom = value(0, 1) * (1f64 - tx) + value(1, 1) * tx;
+	top * (1f64 - ty) + bottom * ty
+}
+
+/// the Catmull-Rom (Keys `a = -½`) cubic convolution weights of the four samples at offsets -1, 0, 1 and 2 from a position `t` past the second
+fn cubic_weights(t: f64) -> [f64; 4] {
+	let (t2, t3) = (t * t, t * t * t);
+	[
+		(-t3 + 2f64 * t2 - t) / 2f64,
+		(3f64 * t3 - 5f64 * t2 + 2f64) / 2f64,
+		(-3f64 * t3 + 4f64 * t2 + t) / 2f64,
+		(t3 - t2) / 2f64
+	]
+}
+
+/// Samples the `width` by `height` row-major `grid` of values at the fractional position `p` like `bilinear_sample`, but by
+/// cubic convolution over the surrounding 4x4 cells, which passes through the cell values with a continuous slope (C¹).
+/// Unlike bilinear sampling it can overshoot the neighbouring values slightly around sharp changes.
+/// Positions beyond the grid are clamped to its edge (as are the neighbourhoods near it), and an empty grid samples as zero.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raster::bicubic_sample;
+/// 	let ramp = [0f64, 1f64, 2f64, 3f64];
+/// 	assert_eq!(bicubic_sample(&ramp, 4, 1, Vec2D::new(2f64, 0f64)), 2f64);
+/// 	assert_eq!(bicubic_sample(&ramp, 4, 1, Vec2D::new(1.5, 0f64)), 1.5);
+/// ```
+pub fn bicubic_sample(grid: &[f64], width: usize, height: usize, p: Vec2D<f64>) -> f64 {
+	if width == 0 || height == 0 {
+		return 0f64;
+	}
+	let x = p.x.clamp(0f64, (width - 1) as f64);
+	let y = p.y.clamp(0f64, (height - 1) as f64);
+	let (x0, y0) = (x.floor(), y.floor());
+	let (wx, wy) = (cubic_weights(x - x0), cubic_weights(y - y0));
+	let (ix, iy) = (x0 as i64, y0 as i64);
+	(0..4).map(|j| {
+		let row: f64 = (0..4).map(|i| wx[i] * grid_value(grid, width, height, ix + i as i64 - 1, iy + j as i64 - 1)).sum();
+		wy[j] * row
+	}).sum()
+}
+
+/// Accumulates `points` into a density grid of `resolution` cells spanning `bounds` (row-major, as for `bilinear_sample`),
+/// spreading each point over the cells around it with a Gaussian splat of standard deviation `radius` (cut off at three of them).
+/// Each splat is normalised over the cells it reaches within the grid, so every point inside the bounds adds exactly one to the
+/// total; points whose splat falls entirely outside the grid are dropped. A zero `radius` counts each point in the cell containing it.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::raster::splat_to_grid;
+/// 	let bounds = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 2f64));
+/// 	let density = splat_to_grid(&[Vec2D::new(2.5, 0.5), Vec2D::new(2.9, 0.1)], &bounds, Vec2D::new(4, 2), 0f64);
+/// 	assert_eq!(density, vec![0f64, 0f64, 2f64, 0f64, 0f64, 0f64, 0f64, 0f64]);
+/// ```
+pub fn splat_to_grid(points: &[Vec2D<f64>], bounds: &AABB<f64>, resolution: Vec2D<usize>, radius: f64) -> Vec<f64> {
+	let mut grid = vec![0f64; resolution.x * resolution.y];
+	if grid.is_empty() {
+		return grid;
+	}
+	let size = bounds.max - bounds.min;
+	let cell = Vec2D::new(size.x / resolution.x as f64, size.y / resolution.y as f64);
+	let cell_of = |value: f64, min: f64, cell: f64, count: usize| ((value - min) / cell).floor().clamp(0f64, (count - 1) as f64) as usize;
+	for &point in points {
+		if radius <= 0f64 {
+			if bounds.contains(point) {
+				grid[cell_of(point.y, bounds.min.y, cell.y, resolution.y) * resolution.x + cell_of(point.x, bounds.min.x, cell.x, resolution.x)] += 1f64;
+			}
+			continue;
+		}
+		let reach = 3f64 * radius;
+		let (x_start, x_end) = (cell_of(point.x - reach, bounds.min.x, cell.x, resolution.x), cell_of(point.x + reach, bounds.min.x, cell.x, resolution.x));
+		let (y_start, y_end) = (cell_of(point.y - reach, bounds.min.y, cell.y, resolution.y), cell_of(point.y + reach, bounds.min.y, cell.y, resolution.y));
+		let mut weights = Vec::with_capacity((x_end - x_start + 1) * (y_end - y_start + 1));
+		for y in y_start..=y_end {
+			for x in x_start..=x_end {
+				let center = bounds.min + Vec2D::new((x as f64 + 0.5) * cell.x, (y as f64 + 0.5) * cell.y);
+				let offset = center - point;
+				let distance_sq = Vec2D::dot_product(offset, offset);
+				if distance_sq <= reach * reach {
+					weights.push((y * resolution.x + x, (-distance_sq / (2f64 * radius * radius)).exp()));
+				}
+			}
+		}
+		let total: f64 = weights.iter().map(|&(_, weight)| weight).sum();
+		if total > 0f64 {
+			for (index, weight) in weights {
+				grid[index] += weight / total;
+			}
+		}
+	}
+	grid
+}
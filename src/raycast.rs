@@ -0,0 +1,121 @@
+use vec2d::Vec2D;
+use shapes::Circle;
+use polygon::{point_in_polygon, closest_point_on_polygon};
+
+/// Casts the ray `origin + direction * t` (for `t >= 0`) at `circle`, returning the `t` of the first point where it meets the boundary,
+/// or `None` if it misses. `t` is the hit distance when `direction` is a unit vector. A ray starting inside the circle hits it on the way out,
+/// and a ray grazing the circle counts as a hit.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::Circle;
+/// 	use phys2d::raycast::ray_circle_intersection;
+/// 	let circle = Circle::new(Vec2D::new(10f64, 0f64), 2f64);
+/// 	assert_eq!(ray_circle_intersection(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), &circle), Some(8f64));
+/// 	assert_eq!(ray_circle_intersection(Vec2D::new(0f64, 0f64), Vec2D::new(-1f64, 0f64), &circle), None);
+/// ```
+pub fn ray_circle_intersection(origin: Vec2D<f64>, direction: Vec2D<f64>, circle: &Circle<f64>) -> Option<f64> {
+	// solve |origin + direction * t - center|^2 = radius^2 for t
+	let offset = origin - circle.center;
+	let a = Vec2D::dot_product(direction, direction);
+	if a == 0f64 {
+		return None;
+	}
+	let half_b = Vec2D::dot_product(offset, direction);
+	let c = Vec2D::dot_product(offset, offset) - circle.radius * circle.radius;
+	let discriminant = half_b * half_b - a * c;
+	if discriminant < 0f64 {
+		return None;
+	}
+	let root = discriminant.sqrt();
+	let (near, far) = ((-half_b - root) / a, (-half_b + root) / a);
+	if near >= 0f64 {
+		Some(near)
+	} else if far >= 0f64 {
+		Some(far)
+	} else {
+		None
+	}
+}
+
+/// the ray parameter `t >= 0` at which `origin + direction * t` crosses the segment from `a` to `b`, if it does (parallel segments never are)
+fn ray_segment_intersection(origin: Vec2D<f64>, direction: Vec2D<f64>, a: Vec2D<f64>, b: Vec2D<f64>) -> Option<f64> {
+	let edge = b - a;
+	let denominator = Vec2D::cross_product(direction, edge);
+	if denominator == 0f64 {
+		return None;
+	}
+	let to_edge = a - origin;
+	let t = Vec2D::cross_product(to_edge, edge) / denominator;
+	let s = Vec2D::cross_product(to_edge, direction) / denominator;
+	if t >= 0f64 && (0f64..=1f64).contains(&s) { Some(t) } else { None }
+}
+
+/// Casts the ray `origin + direction * t` (for `t >= 0`) at the edges of `polygon` (including the closing one),
+/// returning the `t` of the nearest hit along with the unit normal of the edge hit, turned to face back against the ray.
+/// `t` is the hit distance when `direction` is a unit vector. Edges parallel to the ray are never hit.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raycast::ray_polygon_intersection;
+/// 	let square = [Vec2D::new(2f64, -1f64), Vec2D::new(4f64, -1f64), Vec2D::new(4f64, 1f64), Vec2D::new(2f64, 1f64)];
+/// 	let (t, normal) = ray_polygon_intersection(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), &square).unwrap();
+/// 	assert_eq!(t, 2f64);
+/// 	assert_eq!(normal, Vec2D::new(-1f64, 0f64));
+/// ```
+pub fn ray_polygon_intersection(origin: Vec2D<f64>, direction: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> Option<(f64, Vec2D<f64>)> {
+	let mut nearest: Option<(f64, Vec2D<f64>)> = None;
+	for i in 0..polygon.len() {
+		let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+		let t = match ray_segment_intersection(origin, direction, a, b) {
+			Some(t) if nearest.map_or(true, |(best, _)| t < best) => t,
+			_ => continue
+		};
+		let mut normal = (b - a).perp().to_unit();
+		if Vec2D::dot_product(normal, direction) > 0f64 {
+			normal = -normal;
+		}
+		nearest = Some((t, normal));
+	}
+	nearest
+}
+
+/// Sweeps a circle of `radius` from `center` to `center + velocity` against the solid `polygon`, returning the earliest time of impact in `[0, 1]`
+/// (the fraction of `velocity` travelled before first touching), or `None` if it never touches. A circle already touching or inside the polygon gives 0.
+/// Testing the whole sweep catches thin walls that a fast circle would otherwise tunnel through between frames.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::raycast::sweep_circle_polygon;
+/// 	let wall = [Vec2D::new(5f64, -10f64), Vec2D::new(6f64, -10f64), Vec2D::new(6f64, 10f64), Vec2D::new(5f64, 10f64)];
+/// 	assert_eq!(sweep_circle_polygon(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(8f64, 0f64), &wall), Some(0.5));
+/// ```
+pub fn sweep_circle_polygon(center: Vec2D<f64>, radius: f64, velocity: Vec2D<f64>, polygon: &[Vec2D<f64>]) -> Option<f64> {
+	if polygon.is_empty() {
+		return None;
+	}
+	if point_in_polygon(center, polygon) || (closest_point_on_polygon(center, polygon) - center).magnitude() <= radius {
+		return Some(0f64);
+	}
+	// the circle touches the polygon exactly when its center reaches the polygon grown by `radius`:
+	// each edge pushed out either side, joined by circles around the vertices
+	let mut earliest: Option<f64> = None;
+	let mut consider = |t: Option<f64>| {
+		if let Some(t) = t {
+			if t <= 1f64 && earliest.map_or(true, |best| t < best) {
+				earliest = Some(t);
+			}
+		}
+	};
+	for i in 0..polygon.len() {
+		let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+		consider(ray_circle_intersection(center, velocity, &Circle::new(a, radius)));
+		if a == b {
+			continue;
+		}
+		let offset = (b - a).perp().to_unit() * radius;
+		consider(ray_segment_intersection(center, velocity, a + offset, b + offset));
+		consider(ray_segment_intersection(center, velocity, a - offset, b - offset));
+	}
+	earliest
+}
@@ -0,0 +1,225 @@
+use vec2d::Vec2D;
+use collision::Manifold;
+
+/// the fraction of the remaining penetration that `solve_contacts` corrects on each call
+const BAUMGARTE: f64 = 0.2;
+/// the penetration `solve_contacts` tolerates, so resting contacts stay touching rather than jittering in and out of contact
+const PENETRATION_SLOP: f64 = 0.01;
+
+/// The scalar torque `r × F` exerted by `force` applied at the end of `lever_arm` (measured from the pivot).
+/// Positive torque turns counter-clockwise.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::rigid_body::torque;
+/// 	assert_eq!(torque(Vec2D::new(2f64, 0f64), Vec2D::new(0f64, 3f64)), 6f64);
+/// 	assert_eq!(torque(Vec2D::new(2f64, 0f64), Vec2D::new(3f64, 0f64)), 0f64);
+/// ```
+pub fn torque(lever_arm: Vec2D<f64>, force: Vec2D<f64>) -> f64 {
+	Vec2D::cross_product(lever_arm, force)
+}
+
+/// a rigid body with linear and angular state, integrated from the forces applied to it each step
+/// Note: a `mass` (or `inertia`) of zero is treated as infinite, making the body immovable (or unrotatable)
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct RigidBody2D {
+	pub position: Vec2D<f64>,
+	pub velocity: Vec2D<f64>,
+	/// orientation in radians, counter-clockwise
+	pub rotation: f64,
+	/// radians per unit time, counter-clockwise
+	pub angular_velocity: f64,
+	pub mass: f64,
+	/// moment of inertia about the centre of mass (`position`)
+	pub inertia: f64,
+	/// the force accumulated since the last `integrate`
+	pub force: Vec2D<f64>,
+	/// the torque accumulated since the last `integrate`
+	pub torque: f64
+}
+
+impl RigidBody2D {
+	/// Creates a body at rest at `position` with the given mass and moment of inertia.
+	pub fn new(position: Vec2D<f64>, mass: f64, inertia: f64) -> RigidBody2D {
+		RigidBody2D{position, mass, inertia, ..RigidBody2D::default()}
+	}
+
+	/// `1/mass`, or zero for an immovable (zero mass) body
+	pub fn inverse_mass(&self) -> f64 {
+		if self.mass == 0f64 { 0f64 } else { 1f64 / self.mass }
+	}
+
+	/// `1/inertia`, or zero for an unrotatable (zero inertia) body
+	pub fn inverse_inertia(&self) -> f64 {
+		if self.inertia == 0f64 { 0f64 } else { 1f64 / self.inertia }
+	}
+
+	/// Applies `force` at the world-space `point`, accumulating both the force and the torque it exerts about the centre of mass.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::rigid_body::RigidBody2D;
+	/// 	let mut body = RigidBody2D::new(Vec2D::new(0f64, 0f64), 1f64, 1f64);
+	/// 	body.apply_force(Vec2D::new(0f64, 2f64), Vec2D::new(1f64, 0f64));
+	/// 	assert_eq!(body.force, Vec2D::new(0f64, 2f64));
+	/// 	assert_eq!(body.torque, 2f64);
+	/// ```
+	pub fn apply_force(&mut self, force: Vec2D<f64>, point: Vec2D<f64>) {
+		self.force += force;
+		self.torque += torque(point - self.position, force);
+	}
+
+	/// Applies an instantaneous `impulse` at the world-space `point`, immediately changing the linear and angular velocities.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::rigid_body::RigidBody2D;
+	/// 	let mut body = RigidBody2D::new(Vec2D::new(0f64, 0f64), 2f64, 4f64);
+	/// 	body.apply_impulse(Vec2D::new(0f64, 2f64), Vec2D::new(1f64, 0f64));
+	/// 	assert_eq!(body.velocity, Vec2D::new(0f64, 1f64));
+	/// 	assert_eq!(body.angular_velocity, 0.5);
+	/// ```
+	pub fn apply_impulse(&mut self, impulse: Vec2D<f64>, point: Vec2D<f64>) {
+		self.velocity += impulse * self.inverse_mass();
+		self.angular_velocity += torque(point - self.position, impulse) * self.inverse_inertia();
+	}
+
+	/// Advances the body by `dt` using semi-implicit Euler integration of the accumulated force and torque, which are then cleared.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::rigid_body::RigidBody2D;
+	/// 	let mut body = RigidBody2D::new(Vec2D::new(0f64, 0f64), 2f64, 1f64);
+	/// 	body.apply_force(Vec2D::new(4f64, 0f64), body.position);
+	/// 	body.integrate(0.5);
+	/// 	assert_eq!(body.velocity, Vec2D::new(1f64, 0f64));
+	/// 	assert_eq!(body.position, Vec2D::new(0.5, 0f64));
+	/// 	assert_eq!(body.force, Vec2D::new(0f64, 0f64));
+	/// ```
+	pub fn integrate(&mut self, dt: f64) {
+		self.velocity += self.force * (self.inverse_mass() * dt);
+		self.angular_velocity += self.torque * self.inverse_inertia() * dt;
+		self.position += self.velocity * dt;
+		self.rotation += self.angular_velocity * dt;
+		self.force = Vec2D::default();
+		self.torque = 0f64;
+	}
+}
+
+/// The velocity of a `point` on a body moving at `linear_velocity` while rotating at `angular_velocity` about `center`,
+/// i.e. `v + ω × r` where `r = point - center`.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::rigid_body::velocity_at_point;
+/// 	let v = velocity_at_point(Vec2D::new(1f64, 0f64), 2f64, Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 1f64));
+/// 	assert_eq!(v, Vec2D::new(-1f64, 0f64));
+/// ```
+pub fn velocity_at_point(linear_velocity: Vec2D<f64>, angular_velocity: f64, center: Vec2D<f64>, point: Vec2D<f64>) -> Vec2D<f64> {
+	linear_velocity + (point - center).perp() * angular_velocity
+}
+
+/// Resolves a collision between two bodies touching at `contact`, applying equal and opposite impulses along `normal`
+/// (which points from `a` towards `b`) to both their linear and angular velocities.
+/// `restitution` is the coefficient of restitution: 1 for a perfectly elastic bounce, 0 for a perfectly inelastic one.
+/// Bodies that are already separating at the contact point are left untouched.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::rigid_body::{RigidBody2D, resolve_collision};
+/// 	let mut a = RigidBody2D::new(Vec2D::new(0f64, 0f64), 1f64, 1f64);
+/// 	let mut b = RigidBody2D::new(Vec2D::new(2f64, 0f64), 1f64, 1f64);
+/// 	a.velocity = Vec2D::new(1f64, 0f64);
+/// 	resolve_collision(&mut a, &mut b, Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 0f64), 1f64);
+/// 	assert_eq!((a.velocity, b.velocity), (Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64)));
+/// ```
+pub fn resolve_collision(a: &mut RigidBody2D, b: &mut RigidBody2D, contact: Vec2D<f64>, normal: Vec2D<f64>, restitution: f64) {
+	let normal = normal.to_unit();
+	let (arm_a, arm_b) = (contact - a.position, contact - b.position);
+	let relative_velocity = velocity_at_point(b.velocity, b.angular_velocity, b.position, contact)
+		- velocity_at_point(a.velocity, a.angular_velocity, a.position, contact);
+	let closing_speed = Vec2D::dot_product(relative_velocity, normal);
+	if closing_speed >= 0f64 {
+		return;
+	}
+	let (arm_a_normal, arm_b_normal) = (torque(arm_a, normal), torque(arm_b, normal));
+	let effective_inverse_mass = a.inverse_mass() + b.inverse_mass()
+		+ arm_a_normal * arm_a_normal * a.inverse_inertia()
+		+ arm_b_normal * arm_b_normal * b.inverse_inertia();
+	if effective_inverse_mass == 0f64 {
+		return;
+	}
+	let impulse = normal * (-(1f64 + restitution) * closing_speed / effective_inverse_mass);
+	a.apply_impulse(-impulse, contact);
+	b.apply_impulse(impulse, contact);
+}
+
+/// mutable references to two distinct elements of `items`
+pub(crate) fn pair_mut<T>(items: &mut [T], a: usize, b: usize) -> (&mut T, &mut T) {
+	if a < b {
+		let (head, tail) = items.split_at_mut(b);
+		(&mut head[a], &mut tail[0])
+	} else {
+		let (head, tail) = items.split_at_mut(a);
+		(&mut tail[0], &mut head[b])
+	}
+}
+
+/// Resolves the contacts in `manifolds` (each naming its pair of `bodies` by index) with sequential impulses:
+/// every contact point is visited `iterations` times, accumulating a non-negative (push-only) normal impulse that removes the
+/// approach speed there, so that impulses propagate through stacks of bodies. The contacts are treated as perfectly inelastic.
+/// Penetration beyond a small slop is then corrected Baumgarte-style, moving the bodies apart by a fraction of the depth
+/// in proportion to their inverse masses, so repeated calls settle bodies at rest without them sinking into each other.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::rigid_body::{RigidBody2D, solve_contacts};
+/// 	use phys2d::collision::Manifold;
+/// 	let mut bodies = [RigidBody2D::new(Vec2D::new(0f64, 0f64), 0f64, 0f64), RigidBody2D::new(Vec2D::new(0f64, 1f64), 1f64, 0f64)];
+/// 	bodies[1].velocity = Vec2D::new(0f64, -2f64);
+/// 	let points = vec![Vec2D::new(-0.5, 0.5), Vec2D::new(0.5, 0.5)];
+/// 	let manifold = Manifold{normal: Vec2D::new(0f64, 1f64), depth: 0f64, points, body_a: 0, body_b: 1};
+/// 	solve_contacts(&mut bodies, &[manifold], 4);
+/// 	assert_eq!(bodies[1].velocity, Vec2D::new(0f64, 0f64));
+/// 	assert_eq!(bodies[0].velocity, Vec2D::new(0f64, 0f64));
+/// ```
+pub fn solve_contacts(bodies: &mut [RigidBody2D], manifolds: &[Manifold], iterations: usize) {
+	let mut accumulated: Vec<Vec<f64>> = manifolds.iter().map(|m| vec![0f64; m.points.len()]).collect();
+	for _ in 0..iterations {
+		for (manifold, accumulated) in manifolds.iter().zip(accumulated.iter_mut()) {
+			if manifold.body_a == manifold.body_b {
+				continue;
+			}
+			let (a, b) = pair_mut(bodies, manifold.body_a, manifold.body_b);
+			let normal = manifold.normal;
+			for (&contact, total) in manifold.points.iter().zip(accumulated.iter_mut()) {
+				let (arm_a_normal, arm_b_normal) = (torque(contact - a.position, normal), torque(contact - b.position, normal));
+				let effective_inverse_mass = a.inverse_mass() + b.inverse_mass()
+					+ arm_a_normal * arm_a_normal * a.inverse_inertia()
+					+ arm_b_normal * arm_b_normal * b.inverse_inertia();
+				if effective_inverse_mass == 0f64 {
+					continue;
+				}
+				let relative_velocity = velocity_at_point(b.velocity, b.angular_velocity, b.position, contact)
+					- velocity_at_point(a.velocity, a.angular_velocity, a.position, contact);
+				// clamp the running total rather than each step, so later iterations can take back an earlier overshoot
+				let previous = *total;
+				*total = (previous - Vec2D::dot_product(relative_velocity, normal) / effective_inverse_mass).max(0f64);
+				let impulse = normal * (*total - previous);
+				a.apply_impulse(-impulse, contact);
+				b.apply_impulse(impulse, contact);
+			}
+		}
+	}
+
+	for manifold in manifolds.iter().filter(|m| m.body_a != m.body_b) {
+		let (a, b) = pair_mut(bodies, manifold.body_a, manifold.body_b);
+		let total_inverse_mass = a.inverse_mass() + b.inverse_mass();
+		if total_inverse_mass == 0f64 {
+			continue;
+		}
+		let correction = manifold.normal * ((manifold.depth - PENETRATION_SLOP).max(0f64) * BAUMGARTE / total_inverse_mass);
+		a.position -= correction * a.inverse_mass();
+		b.position += correction * b.inverse_mass();
+	}
+}
@@ -0,0 +1,77 @@
+use vec2d::Vec2D;
+use shapes::AABB;
+use noise::lattice_value;
+
+/// the number of candidates tried around each active point before it is retired
+const POISSON_ATTEMPTS: usize = 30;
+
+/// Scatters points over `bounds` so that no two are closer than `min_distance`, using Bridson's Poisson-disk sampling
+/// with a background grid for neighbour lookups. The same `seed` always gives the same points.
+/// Returns no points if `min_distance` is not positive or the bounds are empty.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::AABB;
+/// 	use phys2d::sampling::poisson_disk_sample;
+/// 	let bounds = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 10f64));
+/// 	let points = poisson_disk_sample(bounds, 1f64, 7);
+/// 	assert!(points.len() > 40);
+/// 	assert!(points.iter().all(|&p| bounds.contains(p)));
+/// ```
+pub fn poisson_disk_sample(bounds: AABB<f64>, min_distance: f64, seed: u32) -> Vec<Vec2D<f64>> {
+	let size = bounds.max - bounds.min;
+	if min_distance <= 0f64 || size.x < 0f64 || size.y < 0f64 {
+		return Vec::new();
+	}
+	let mut draws = 0;
+	let mut random = || {
+		draws += 1;
+		lattice_value(draws, 0, seed)
+	};
+
+	// cells small enough to hold at most one point each
+	let cell_size = min_distance / 2f64.sqrt();
+	let (columns, rows) = ((size.x / cell_size) as usize + 1, (size.y / cell_size) as usize + 1);
+	let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+	let cell_of = |p: Vec2D<f64>| (((p.x - bounds.min.x) / cell_size) as usize, ((p.y - bounds.min.y) / cell_size) as usize);
+
+	let mut points = Vec::new();
+	let mut active = Vec::new();
+	let first = bounds.min + Vec2D::new(random() * size.x, random() * size.y);
+	let (column, row) = cell_of(first);
+	grid[row * columns + column] = Some(0);
+	points.push(first);
+	active.push(0);
+
+	while !active.is_empty() {
+		let slot = ((random() * active.len() as f64) as usize).min(active.len() - 1);
+		let origin = points[active[slot]];
+		let mut placed = false;
+		for _ in 0..POISSON_ATTEMPTS {
+			// uniformly within the annulus between one and two times the minimum distance
+			let angle = random() * 2f64 * std::f64::consts::PI;
+			let radius = min_distance * (1f64 + 3f64 * random()).sqrt();
+			let candidate = origin + Vec2D::new(angle.cos(), angle.sin()) * radius;
+			if !bounds.contains(candidate) {
+				continue;
+			}
+			let (column, row) = cell_of(candidate);
+			let too_close = (row.saturating_sub(2)..(row + 3).min(rows)).any(|r| {
+				(column.saturating_sub(2)..(column + 3).min(columns)).any(|c| {
+					grid[r * columns + c].is_some_and(|i| (points[i] - candidate).magnitude() < min_distance)
+				})
+			});
+			if !too_close {
+				grid[row * columns + column] = Some(points.len());
+				active.push(points.len());
+				points.push(candidate);
+				placed = true;
+				break;
+			}
+		}
+		if !placed {
+			active.swap_remove(slot);
+		}
+	}
+	points
+}
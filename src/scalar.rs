@@ -0,0 +1,30 @@
+use std::ops::*;
+use std::fmt::Debug;
+
+/// The scalar types `Vec2D`'s geometric methods (magnitude, angle, normalisation, projection, etc) work over.
+/// Bundles the arithmetic those methods need along with a lossless conversion to `f64`.
+/// Implemented for the primitive types which losslessly convert to `f64`; custom scalar types can implement it themselves.
+/// # Examples
+/// ```
+/// 	use phys2d::Scalar;
+/// 	assert_eq!(3u8.to_f64(), 3f64);
+/// 	assert_eq!((-2i32).to_f64(), -2f64);
+/// ```
+pub trait Scalar: Copy+Debug+PartialEq+PartialOrd+Default + Add<Output=Self>+Sub<Output=Self>+Mul<Output=Self> {
+	/// converts the scalar into an `f64`
+	fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+	($($t:ty),*) => {
+		$(
+			impl Scalar for $t {
+				fn to_f64(self) -> f64 {
+					f64::from(self)
+				}
+			}
+		)*
+	}
+}
+
+impl_scalar!(i8, i16, i32, u8, u16, u32, f32, f64);
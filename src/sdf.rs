@@ -0,0 +1,47 @@
+use vec2d::Vec2D;
+
+/// Signed distance from `p` to the circle at `center` with `radius`: negative inside, zero on the boundary and positive outside.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::sdf::sdf_circle;
+/// 	assert_eq!(sdf_circle(Vec2D::new(5f64, 0f64), Vec2D::new(0f64, 0f64), 2f64), 3f64);
+/// 	assert_eq!(sdf_circle(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64), 2f64), -2f64);
+/// ```
+pub fn sdf_circle(p: Vec2D<f64>, center: Vec2D<f64>, radius: f64) -> f64 {
+	(p - center).magnitude() - radius
+}
+
+/// Signed distance from `p` to the axis-aligned box at `center` extending `half_extents` either side on each axis.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::sdf::sdf_box;
+/// 	let (center, half_extents) = (Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 1f64));
+/// 	assert_eq!(sdf_box(Vec2D::new(5f64, 0f64), center, half_extents), 3f64);
+/// 	assert_eq!(sdf_box(Vec2D::new(0f64, 0f64), center, half_extents), -1f64);
+/// ```
+pub fn sdf_box(p: Vec2D<f64>, center: Vec2D<f64>, half_extents: Vec2D<f64>) -> f64 {
+	let offset = p - center;
+	let d = Vec2D::new(offset.x.abs() - half_extents.x, offset.y.abs() - half_extents.y);
+	let outside = Vec2D::new(d.x.max(0f64), d.y.max(0f64)).magnitude();
+	let inside = d.x.max(d.y).min(0f64);
+	outside + inside
+}
+
+/// Distance from `p` to the line segment from `a` to `b`.
+/// A segment encloses no area, so this is never negative; subtract a thickness from it to get a capsule.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::sdf::sdf_segment;
+/// 	let (a, b) = (Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64));
+/// 	assert_eq!(sdf_segment(Vec2D::new(2f64, 3f64), a, b), 3f64);
+/// 	assert_eq!(sdf_segment(Vec2D::new(7f64, 4f64), a, b), 5f64);
+/// ```
+pub fn sdf_segment(p: Vec2D<f64>, a: Vec2D<f64>, b: Vec2D<f64>) -> f64 {
+	let (pa, ba) = (p - a, b - a);
+	let length_squared = Vec2D::dot_product(ba, ba);
+	let t = if length_squared == 0f64 { 0f64 } else { (Vec2D::dot_product(pa, ba) / length_squared).clamp(0f64, 1f64) };
+	(pa - ba * t).magnitude()
+}
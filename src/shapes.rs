@@ -0,0 +1,131 @@
+use std::ops::*;
+use std::fmt::Debug;
+use vec2d::Vec2D;
+use scalar::Scalar;
+
+/// an axis-aligned bounding box, spanning from `min` to `max` (inclusive) on each axis
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct AABB<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	pub min: Vec2D<T>,
+	pub max: Vec2D<T>
+}
+
+impl<T> AABB<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// Creates a new `AABB` from its minimum and maximum corners.
+	pub fn new(min: Vec2D<T>, max: Vec2D<T>) -> AABB<T> {
+		AABB{min, max}
+	}
+
+	/// Tests whether `point` lies within the box (boundary included).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	let b = AABB::new(Vec2D::new(0, 0), Vec2D::new(4, 2));
+	/// 	assert!(b.contains(Vec2D::new(4, 1)));
+	/// 	assert!(!b.contains(Vec2D::new(5, 1)));
+	/// ```
+	pub fn contains(&self, point: Vec2D<T>) -> bool {
+		point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+	}
+
+	/// Tests whether this box overlaps `other` (touching boundaries count as overlapping).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	let a = AABB::new(Vec2D::new(0, 0), Vec2D::new(4, 2));
+	/// 	assert!(a.intersects(&AABB::new(Vec2D::new(3, 1), Vec2D::new(6, 6))));
+	/// 	assert!(!a.intersects(&AABB::new(Vec2D::new(5, 0), Vec2D::new(6, 6))));
+	/// ```
+	pub fn intersects(&self, other: &AABB<T>) -> bool {
+		self.min.x <= other.max.x && other.min.x <= self.max.x && self.min.y <= other.max.y && other.min.y <= self.max.y
+	}
+
+	/// Grows the box by `margin` on every side (or shrinks it, for a negative margin).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	let b = AABB::new(Vec2D::new(0, 0), Vec2D::new(4, 2)).expanded(1);
+	/// 	assert_eq!(b, AABB::new(Vec2D::new(-1, -1), Vec2D::new(5, 3)));
+	/// ```
+	pub fn expanded(&self, margin: T) -> AABB<T> where T: Add<Output=T>+Sub<Output=T> {
+		let offset = Vec2D::new(margin, margin);
+		AABB::new(self.min - offset, self.max + offset)
+	}
+}
+
+/// a line segment from `start` to `end`
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Segment2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	pub start: Vec2D<T>,
+	pub end: Vec2D<T>
+}
+
+impl<T> Segment2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// Creates a new `Segment2D` between the two points.
+	pub fn new(start: Vec2D<T>, end: Vec2D<T>) -> Segment2D<T> {
+		Segment2D{start, end}
+	}
+}
+
+impl Segment2D<f64> {
+	/// The length of the segment.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::Segment2D;
+	/// 	assert_eq!(Segment2D::new(Vec2D::new(1f64, 1f64), Vec2D::new(4f64, 5f64)).length(), 5f64);
+	/// ```
+	pub fn length(&self) -> f64 {
+		(self.end - self.start).magnitude()
+	}
+
+	/// The point on the segment nearest to `point` (the start, for a zero-length segment).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::Segment2D;
+	/// 	let segment = Segment2D::new(Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64));
+	/// 	assert_eq!(segment.closest_point(Vec2D::new(1f64, 3f64)), Vec2D::new(1f64, 0f64));
+	/// 	assert_eq!(segment.closest_point(Vec2D::new(6f64, 1f64)), Vec2D::new(4f64, 0f64));
+	/// ```
+	pub fn closest_point(&self, point: Vec2D<f64>) -> Vec2D<f64> {
+		let direction = self.end - self.start;
+		let length_sq = Vec2D::dot_product(direction, direction);
+		if length_sq == 0f64 {
+			return self.start;
+		}
+		let t = (Vec2D::dot_product(point - self.start, direction) / length_sq).clamp(0f64, 1f64);
+		self.start + direction * t
+	}
+}
+
+/// a circle of `radius` around `center`
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Circle<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	pub center: Vec2D<T>,
+	pub radius: T
+}
+
+impl<T> Circle<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// Creates a new `Circle` from its center and radius.
+	pub fn new(center: Vec2D<T>, radius: T) -> Circle<T> {
+		Circle{center, radius}
+	}
+
+	/// Tests whether `point` lies within the circle (boundary included).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::Circle;
+	/// 	let c = Circle::new(Vec2D::new(1, 1), 5);
+	/// 	assert!(c.contains(Vec2D::new(4, 5)));
+	/// 	assert!(!c.contains(Vec2D::new(5, 5)));
+	/// ```
+	pub fn contains(&self, point: Vec2D<T>) -> bool where T: Scalar {
+		let offset = point - self.center;
+		Vec2D::dot_product(offset, offset) <= self.radius * self.radius
+	}
+}
@@ -0,0 +1,288 @@
+use vec2d::Vec2D;
+use shapes::Circle;
+use raycast::ray_circle_intersection;
+
+/// Computes the "arrive" steering behaviour: the desired velocity heading from `position` towards `target`
+/// at `max_speed`, scaled down linearly once within `slowing_radius` so that it reaches zero at the target itself.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::steering::arrive;
+/// 	let v = arrive(Vec2D::new(0f64, 0f64), Vec2D::new(100f64, 0f64), 5f64, 10f64);
+/// 	assert_eq!(v, Vec2D::new(5f64, 0f64));
+/// 	let v = arrive(Vec2D::new(95f64, 0f64), Vec2D::new(100f64, 0f64), 5f64, 10f64);
+/// 	assert_eq!(v, Vec2D::new(2.5f64, 0f64));
+/// ```
+pub fn arrive(position: Vec2D<f64>, target: Vec2D<f64>, max_speed: f64, slowing_radius: f64) -> Vec2D<f64> {
+	let offset = target - position;
+	let distance = offset.magnitude();
+	if distance == 0f64 {
+		return Vec2D::default();
+	}
+	let speed = if distance < slowing_radius {
+		max_speed * distance / slowing_radius
+	} else {
+		max_speed
+	};
+	offset * (speed / distance)
+}
+
+/// Solves for the unit direction a projectile fired from `shooter` at `projectile_speed` must travel in to meet
+/// a target currently at `target` moving with constant `target_velocity`.
+/// Returns `None` when there is no positive time of interception (e.g. the target outruns the projectile, or is already at the shooter).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::steering::intercept_direction;
+/// 	let aim = intercept_direction(Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64), Vec2D::new(0f64, 0f64), 2f64);
+/// 	assert_eq!(aim, Some(Vec2D::new(1f64, 0f64)));
+/// ```
+pub fn intercept_direction(shooter: Vec2D<f64>, target: Vec2D<f64>, target_velocity: Vec2D<f64>, projectile_speed: f64) -> Option<Vec2D<f64>> {
+	let offset = target - shooter;
+	let a = Vec2D::dot_product(target_velocity, target_velocity) - projectile_speed * projectile_speed;
+	let b = 2f64 * Vec2D::dot_product(offset, target_velocity);
+	let c = Vec2D::dot_product(offset, offset);
+
+	let time = if a.abs() < 1e-12 {
+		if b == 0f64 {
+			return None;
+		}
+		-c / b
+	} else {
+		let discriminant = b * b - 4f64 * a * c;
+		if discriminant < 0f64 {
+			return None;
+		}
+		let root = discriminant.sqrt();
+		let (t1, t2) = ((-b - root) / (2f64 * a), (-b + root) / (2f64 * a));
+		let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+		if near > 0f64 { near } else { far }
+	};
+
+	if time > 0f64 {
+		Some((offset + target_velocity * time).to_unit())
+	} else {
+		None
+	}
+}
+
+/// Gives the rotation (in radians, counter-clockwise from the positive x axis) an entity at `from` needs in order to face `target`.
+/// When `target` coincides with `from` there is nothing to face, and `0.0` is returned.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::steering::look_at;
+/// 	assert_eq!(look_at(Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 5f64)), std::f64::consts::PI/2f64);
+/// ```
+pub fn look_at(from: Vec2D<f64>, target: Vec2D<f64>) -> f64 {
+	let offset = target - from;
+	if offset == Vec2D::default() {
+		return 0f64;
+	}
+	offset.angle()
+}
+
+/// a flocking agent
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct Boid {
+	pub position: Vec2D<f64>,
+	pub velocity: Vec2D<f64>
+}
+
+impl Boid {
+	/// Creates a new `Boid` at `position` moving with `velocity`.
+	pub fn new(position: Vec2D<f64>, velocity: Vec2D<f64>) -> Boid {
+		Boid{position, velocity}
+	}
+}
+
+/// tuning for `flock_step`
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct FlockParams {
+	/// neighbours further away than this are ignored entirely
+	pub perception_radius: f64,
+	/// neighbours closer than this are steered away from
+	pub separation_radius: f64,
+	pub separation_weight: f64,
+	pub alignment_weight: f64,
+	pub cohesion_weight: f64
+}
+
+/// Computes the flocking acceleration for `boid` from the classic boids rules, each weighted by `params`:
+/// separation (steering away from crowding neighbours, more strongly the closer they are),
+/// alignment (matching the average velocity of neighbours) and cohesion (steering towards their average position).
+/// `neighbors` may include `boid` itself, as any neighbour at exactly its position is skipped.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::steering::{flock_step, Boid, FlockParams};
+/// 	let params = FlockParams{perception_radius: 10f64, separation_radius: 0f64, separation_weight: 0f64, alignment_weight: 0f64, cohesion_weight: 1f64};
+/// 	let boid = Boid::new(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64));
+/// 	let others = [Boid::new(Vec2D::new(4f64, 0f64), Vec2D::new(0f64, 0f64)), Boid::new(Vec2D::new(0f64, 4f64), Vec2D::new(0f64, 0f64))];
+/// 	assert_eq!(flock_step(&boid, &others, &params), Vec2D::new(2f64, 2f64));
+/// ```
+pub fn flock_step(boid: &Boid, neighbors: &[Boid], params: &FlockParams) -> Vec2D<f64> {
+	let mut separation = Vec2D::default();
+	let (mut velocity_sum, mut position_sum, mut count) = (Vec2D::default(), Vec2D::default(), 0);
+	for other in neighbors {
+		let away = boid.position - other.position;
+		let distance = away.magnitude();
+		if distance == 0f64 || distance > params.perception_radius {
+			continue;
+		}
+		if distance < params.separation_radius {
+			separation += away / (distance * distance);
+		}
+		velocity_sum += other.velocity;
+		position_sum += other.position;
+		count += 1;
+	}
+	if count == 0 {
+		return Vec2D::default();
+	}
+	let alignment = velocity_sum / count as f64 - boid.velocity;
+	let cohesion = position_sum / count as f64 - boid.position;
+	separation * params.separation_weight + alignment * params.alignment_weight + cohesion * params.cohesion_weight
+}
+
+/// a controller steering an agent along a path of waypoints in turn, with `current` the index of the waypoint it is heading for
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct PathFollower {
+	pub path: Vec<Vec2D<f64>>,
+	pub current: usize
+}
+
+impl PathFollower {
+	/// Creates a follower heading for the first waypoint of `path`.
+	pub fn new(path: Vec<Vec2D<f64>>) -> PathFollower {
+		PathFollower{path, current: 0}
+	}
+
+	/// Computes the desired velocity for an agent at `position`: `max_speed` towards the current waypoint, moving on to the next
+	/// once within `look_ahead` of it so corners are cut smoothly rather than overshot. The last waypoint is approached with `arrive`
+	/// (slowing within `look_ahead`), bringing the agent to a stop there. An empty path gives zero.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::steering::PathFollower;
+	/// 	let mut follower = PathFollower::new(vec![Vec2D::new(10f64, 0f64), Vec2D::new(10f64, 10f64)]);
+	/// 	assert_eq!(follower.steering(Vec2D::new(0f64, 0f64), 1f64, 2f64), Vec2D::new(2f64, 0f64));
+	/// 	// close enough to the first waypoint to head for the second
+	/// 	let turning = follower.steering(Vec2D::new(9.5, 0f64), 1f64, 2f64);
+	/// 	assert_eq!(follower.current, 1);
+	/// 	assert!(turning.y > 1.9);
+	/// ```
+	pub fn steering(&mut self, position: Vec2D<f64>, look_ahead: f64, max_speed: f64) -> Vec2D<f64> {
+		if self.path.is_empty() {
+			return Vec2D::default();
+		}
+		let last = self.path.len() - 1;
+		self.current = self.current.min(last);
+		while self.current < last && (self.path[self.current] - position).magnitude() <= look_ahead {
+			self.current += 1;
+		}
+		if self.current == last {
+			return arrive(position, self.path[last], max_speed, look_ahead);
+		}
+		(self.path[self.current] - position).to_unit() * max_speed
+	}
+}
+
+/// Chooses a direction to move in with context steering: `num_rays` (at least one) evenly spaced directions around `position` are
+/// each scored by their interest, how closely they point along `desired_dir`, scaled down by their danger, which rises towards one
+/// the closer an obstacle lies along them (relative to its size, as `radius / (distance + radius)`). The best scoring direction
+/// is returned as a unit vector, so an obstacle in the way deflects the agent to one side of it, while distant ones barely matter.
+/// Rays heading deeper into an obstacle the agent is already inside are fully dangerous. Returns zero if no direction has any
+/// interest left (including when `desired_dir` is zero).
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::shapes::Circle;
+/// 	use phys2d::steering::context_steering;
+/// 	let clear = context_steering(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 1f64), &[], 16);
+/// 	assert!((clear - Vec2D::new(0f64, 1f64)).magnitude() < 1e-12);
+/// ```
+pub fn context_steering(position: Vec2D<f64>, desired_dir: Vec2D<f64>, obstacles: &[Circle<f64>], num_rays: usize) -> Vec2D<f64> {
+	if desired_dir.magnitude() == 0f64 {
+		return Vec2D::default();
+	}
+	let desired = desired_dir.to_unit();
+	let num_rays = num_rays.max(1);
+	// start the fan of rays on the desired direction, so an unobstructed agent goes exactly where it wants
+	let heading = desired.angle();
+	let mut best = (Vec2D::default(), 0f64);
+	for i in 0..num_rays {
+		let direction = Vec2D::from_polar(1f64, heading + i as f64 / num_rays as f64 * 2f64 * std::f64::consts::PI);
+		let interest = Vec2D::dot_product(direction, desired).max(0f64);
+		let danger = obstacles.iter().map(|obstacle| {
+			if obstacle.contains(position) {
+				return if Vec2D::dot_product(direction, obstacle.center - position) > 0f64 { 1f64 } else { 0f64 };
+			}
+			ray_circle_intersection(position, direction, obstacle).map_or(0f64, |distance| obstacle.radius / (distance + obstacle.radius))
+		}).fold(0f64, f64::max);
+		let score = interest * (1f64 - danger);
+		if score > best.1 {
+			best = (direction, score);
+		}
+	}
+	best.0
+}
+
+/// how many speeds (up to the preferred one) `reciprocal_velocity` tries, in addition to standing still
+const AVOIDANCE_SPEEDS: usize = 4;
+/// how many directions around the preferred one `reciprocal_velocity` tries at each speed
+const AVOIDANCE_DIRECTIONS: usize = 32;
+/// how strongly `reciprocal_velocity` weighs a sooner collision against straying from the preferred velocity
+const AVOIDANCE_WEIGHT: f64 = 2f64;
+
+/// Picks a velocity for an agent of `radius` at `agent_pos` that keeps close to its preferred velocity `agent_vel` while avoiding
+/// its `neighbors` (each given as its position, velocity and radius), with a sampling take on reciprocal velocity obstacles (RVO).
+/// Candidates at a few speeds and directions around the preferred velocity are each penalised by how far they stray from it plus
+/// how soon they would lead to a collision within `time_horizon`. A collision is judged assuming each neighbour does its half of the
+/// avoiding too, as when they run the same rule, so agents share the effort rather than both swerving fully (and oscillating).
+/// Ties go to turning counter-clockwise, so agents meeting head-on both swerve to their left and pass each other.
+/// # Examples
+/// ```
+/// 	use phys2d::vec2d::Vec2D;
+/// 	use phys2d::steering::reciprocal_velocity;
+/// 	// nobody in the way: go at the preferred velocity
+/// 	let v = reciprocal_velocity(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), &[(Vec2D::new(0f64, 9f64), Vec2D::new(0f64, 0f64), 1f64)], 0.5, 5f64);
+/// 	assert_eq!(v, Vec2D::new(1f64, 0f64));
+/// ```
+pub fn reciprocal_velocity(agent_pos: Vec2D<f64>, agent_vel: Vec2D<f64>, neighbors: &[(Vec2D<f64>, Vec2D<f64>, f64)], radius: f64, time_horizon: f64) -> Vec2D<f64> {
+	let preferred_speed = agent_vel.magnitude();
+	let heading = agent_vel.angle();
+	let mut candidates = vec![agent_vel, Vec2D::default()];
+	for s in 1..=AVOIDANCE_SPEEDS {
+		let speed = preferred_speed * s as f64 / AVOIDANCE_SPEEDS as f64;
+		for d in 1..AVOIDANCE_DIRECTIONS {
+			// alternate either side of the preferred heading, counter-clockwise first, widening as we go
+			let step = d.div_ceil(2) as f64 * 2f64 * std::f64::consts::PI / AVOIDANCE_DIRECTIONS as f64;
+			let offset = if d % 2 == 1 { step } else { -step };
+			candidates.push(Vec2D::from_polar(speed, heading + offset));
+		}
+		candidates.push(Vec2D::from_polar(speed, heading));
+	}
+
+	let penalty = |candidate: Vec2D<f64>| {
+		let time_to_collision = neighbors.iter().filter_map(|&(position, velocity, neighbor_radius)| {
+			// the velocity relative to the neighbour, were both to take half the change from their current velocities
+			let relative = candidate * 2f64 - agent_vel - velocity;
+			let obstacle = Circle::new(position - agent_pos, radius + neighbor_radius);
+			if obstacle.contains(Vec2D::default()) {
+				return Some(0f64);
+			}
+			ray_circle_intersection(Vec2D::default(), relative, &obstacle)
+		}).fold(f64::INFINITY, f64::min);
+		let straying = (candidate - agent_vel).magnitude();
+		if time_to_collision >= time_horizon {
+			straying
+		} else {
+			straying + AVOIDANCE_WEIGHT / time_to_collision.max(f64::EPSILON)
+		}
+	};
+	candidates.into_iter().fold((agent_vel, f64::INFINITY), |best, candidate| {
+		let cost = penalty(candidate);
+		if cost < best.1 { (candidate, cost) } else { best }
+	}).0
+}
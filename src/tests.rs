@@ -1,4 +1,4 @@
-use phys2d::vec2d::*;
+use vec2d::*;
 #[test]
 fn with_int() {
 	let n = 4;
@@ -32,4 +32,2692 @@ fn magnitude_test()
 // fn sub_vecs() {
 // 	let x = phys2d::Vec2D::new(1, 1);
 // 	assert_eq!(x - x, phys2d::Vec2D::new(0,0));
-// }
\ No newline at end of file
+// }
+
+#[test]
+fn wu_line_covers_endpoints() {
+	use raster::wu_line;
+	let (start, end) = (Vec2D::new(1f64, 2f64), Vec2D::new(7f64, 5f64));
+	let pixels = wu_line(start, end);
+	assert!(pixels.iter().any(|&(p, c)| p == Vec2D::new(1, 2) && c > 0f64));
+	assert!(pixels.iter().any(|&(p, c)| p == Vec2D::new(7, 5) && c > 0f64));
+
+	let steep = wu_line(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 9f64));
+	assert!(steep.iter().any(|&(p, _)| p == Vec2D::new(0, 0)));
+	assert!(steep.iter().any(|&(p, _)| p == Vec2D::new(2, 9)));
+}
+
+#[test]
+fn wu_line_coverage_in_range() {
+	use raster::wu_line;
+	for &(start, end) in &[
+		(Vec2D::new(0.3f64, 0.7f64), Vec2D::new(10.2f64, 3.9f64)),
+		(Vec2D::new(5f64, -2f64), Vec2D::new(-3.5f64, 8.25f64)),
+		(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64)),
+	] {
+		for &(_, coverage) in &wu_line(start, end) {
+			assert!(coverage > 0f64 && coverage <= 1f64);
+		}
+	}
+}
+
+#[test]
+fn value_noise_is_deterministic() {
+	use noise::value_noise_2d;
+	for i in 0..50 {
+		let p = Vec2D::new(i as f64 * 0.37 - 4f64, i as f64 * -0.91 + 2f64);
+		let n = value_noise_2d(p, 1234);
+		assert_eq!(n, value_noise_2d(p, 1234));
+		assert!((0f64..=1f64).contains(&n));
+	}
+	assert!(value_noise_2d(Vec2D::new(0.5, 0.5), 1) != value_noise_2d(Vec2D::new(0.5, 0.5), 2));
+}
+
+#[test]
+fn value_noise_is_smooth() {
+	use noise::value_noise_2d;
+	let step = 0.001;
+	for i in 0..200 {
+		let p = Vec2D::new(i as f64 * 0.05, 3f64 - i as f64 * 0.03);
+		let a = value_noise_2d(p, 42);
+		let b = value_noise_2d(p + Vec2D::new(step, step), 42);
+		assert!((a - b).abs() < 0.01);
+	}
+}
+
+#[test]
+fn arrive_slows_within_radius() {
+	use steering::arrive;
+	let target = Vec2D::new(10f64, 10f64);
+	let mut last = f64::INFINITY;
+	for i in 0..10 {
+		let position = Vec2D::new(i as f64 * 0.5, i as f64 * 0.5) + Vec2D::new(5f64, 5f64);
+		let speed = arrive(position, target, 4f64, 8f64).magnitude();
+		assert!(speed < last);
+		last = speed;
+	}
+	assert_eq!(arrive(target, target, 4f64, 8f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn arrive_caps_at_max_speed() {
+	use steering::arrive;
+	let v = arrive(Vec2D::new(-50f64, 20f64), Vec2D::new(30f64, -40f64), 3f64, 10f64);
+	assert!((v.magnitude() - 3f64).abs() < 1e-12);
+}
+
+#[test]
+fn intercept_stationary_target() {
+	use steering::intercept_direction;
+	let aim = intercept_direction(Vec2D::new(1f64, 1f64), Vec2D::new(4f64, 5f64), Vec2D::new(0f64, 0f64), 10f64).unwrap();
+	assert!((aim.x - 0.6).abs() < 1e-12 && (aim.y - 0.8).abs() < 1e-12);
+}
+
+#[test]
+fn intercept_crossing_target_leads() {
+	use steering::intercept_direction;
+	let (shooter, target, velocity, speed) = (Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64), Vec2D::new(0f64, 3f64), 5f64);
+	let aim = intercept_direction(shooter, target, velocity, speed).unwrap();
+	assert!(aim.y > 0f64);
+	// the projectile meets the target after t = 2.5, at (10, 7.5)
+	let t = 2.5;
+	let hit = shooter + aim * (speed * t);
+	let expected = target + velocity * t;
+	assert!((hit - expected).magnitude() < 1e-9);
+
+	assert_eq!(intercept_direction(shooter, target, Vec2D::new(10f64, 0f64), 5f64), None);
+}
+
+#[test]
+fn frictionless_bounce_matches_reflect() {
+	use physics::{bounce, reflect_velocity};
+	let (velocity, normal) = (Vec2D::new(3f64, -7f64), Vec2D::new(1f64, 2f64));
+	let bounced = bounce(velocity, normal, 1f64, 0f64);
+	let reflected = reflect_velocity(velocity, normal);
+	assert!((bounced - reflected).magnitude() < 1e-12);
+	assert!((bounced.magnitude() - velocity.magnitude()).abs() < 1e-12);
+}
+
+#[test]
+fn high_friction_bounce_kills_tangent() {
+	use physics::bounce;
+	let v = bounce(Vec2D::new(5f64, -2f64), Vec2D::new(0f64, 1f64), 0.8, 1f64);
+	assert!(v.x.abs() < 1e-12);
+	assert!((v.y - 1.6).abs() < 1e-12);
+}
+
+#[test]
+fn elastic_head_on_equal_masses_swap() {
+	use physics::resolve_elastic_collision;
+	let (v1, v2) = (Vec2D::new(3f64, 0f64), Vec2D::new(-1f64, 0f64));
+	let (a, b) = resolve_elastic_collision(Vec2D::new(0f64, 0f64), v1, 2f64, Vec2D::new(1f64, 0f64), v2, 2f64);
+	assert_eq!((a, b), (v2, v1));
+}
+
+#[test]
+fn elastic_glancing_preserves_tangent() {
+	use physics::resolve_elastic_collision;
+	let (p1, v1, m1) = (Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 1f64), 1f64);
+	let (p2, v2, m2) = (Vec2D::new(1f64, 1f64), Vec2D::new(0f64, -2f64), 3f64);
+	let (a, b) = resolve_elastic_collision(p1, v1, m1, p2, v2, m2);
+
+	let normal = (p2 - p1).to_unit();
+	let tangent = Vec2D::new(-normal.y, normal.x);
+	assert!((Vec2D::dot_product(a, tangent) - Vec2D::dot_product(v1, tangent)).abs() < 1e-12);
+	assert!((Vec2D::dot_product(b, tangent) - Vec2D::dot_product(v2, tangent)).abs() < 1e-12);
+
+	let momentum_before = v1 * m1 + v2 * m2;
+	let momentum_after = a * m1 + b * m2;
+	assert!((momentum_before - momentum_after).magnitude() < 1e-12);
+	let energy = |u: Vec2D<f64>, w: Vec2D<f64>| m1 * Vec2D::dot_product(u, u) + m2 * Vec2D::dot_product(w, w);
+	assert!((energy(v1, v2) - energy(a, b)).abs() < 1e-9);
+}
+
+#[test]
+fn separate_overlapping_circles_become_tangent() {
+	use physics::separate_circles;
+	let (r1, r2) = (2f64, 1.5);
+	for &(p1, p2) in &[
+		(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 2f64)),
+		(Vec2D::new(-3f64, 4f64), Vec2D::new(-3.5, 3f64)),
+		(Vec2D::new(5f64, 5f64), Vec2D::new(5f64, 5f64)),
+	] {
+		let (a, b) = separate_circles(p1, r1, p2, r2);
+		assert!(((b - a).magnitude() - (r1 + r2)).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn separate_leaves_disjoint_circles() {
+	use physics::separate_circles;
+	let (p1, p2) = (Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64));
+	assert_eq!(separate_circles(p1, 2f64, p2, 3f64), (p1, p2));
+}
+
+#[test]
+fn spring_force_zero_at_rest() {
+	use physics::spring_force;
+	let (a, b) = (Vec2D::new(1f64, 1f64), Vec2D::new(4f64, 5f64));
+	assert!(spring_force(a, b, 5f64, 10f64).magnitude() < 1e-12);
+}
+
+#[test]
+fn spring_force_attracts_and_repels() {
+	use physics::spring_force;
+	let (a, b) = (Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 4f64));
+	// stretched: a is pulled towards b
+	assert!(spring_force(a, b, 2f64, 1f64).y > 0f64);
+	// compressed: a is pushed away from b
+	assert!(spring_force(a, b, 6f64, 1f64).y < 0f64);
+}
+
+#[test]
+fn gravity_force_inverse_square() {
+	use physics::gravity_force;
+	let a = Vec2D::new(0f64, 0f64);
+	let near = gravity_force(a, Vec2D::new(1f64, 0f64), 1f64, 1f64, 1f64);
+	let far = gravity_force(a, Vec2D::new(2f64, 0f64), 1f64, 1f64, 1f64);
+	assert!(near.x > 0f64);
+	assert!((near.magnitude() / far.magnitude() - 4f64).abs() < 1e-12);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+struct Metres(f64);
+
+impl std::ops::Add for Metres {
+	type Output = Metres;
+	fn add(self, rhs: Metres) -> Metres { Metres(self.0 + rhs.0) }
+}
+
+impl std::ops::Sub for Metres {
+	type Output = Metres;
+	fn sub(self, rhs: Metres) -> Metres { Metres(self.0 - rhs.0) }
+}
+
+impl std::ops::Mul for Metres {
+	type Output = Metres;
+	fn mul(self, rhs: Metres) -> Metres { Metres(self.0 * rhs.0) }
+}
+
+impl ::scalar::Scalar for Metres {
+	fn to_f64(self) -> f64 { self.0 }
+}
+
+#[test]
+fn custom_scalar_type() {
+	let v = Vec2D::new(Metres(3f64), Metres(4f64));
+	assert_eq!(v.magnitude(), 5f64);
+	assert_eq!(v.angle(), 4f64.atan2(3f64));
+	assert_eq!(v.to_unit(), Vec2D::new(0.6, 0.8));
+	assert_eq!(v.project_onto(Vec2D::new(Metres(2f64), Metres(0f64))), Vec2D::new(3f64, 0f64));
+}
+
+#[test]
+fn angle_bin_cardinals() {
+	assert_eq!(Vec2D::new(1, 0).angle_bin(8), 0);
+	assert_eq!(Vec2D::new(0, 1).angle_bin(8), 2);
+	assert_eq!(Vec2D::new(-1, 0).angle_bin(8), 4);
+	assert_eq!(Vec2D::new(0, -1).angle_bin(8), 6);
+	assert_eq!(Vec2D::new(1, 1).angle_bin(8), 1);
+	assert_eq!(Vec2D::new(1f64, -1e-9).angle_bin(8), 7);
+	assert_eq!(Vec2D::new(0, 0).angle_bin(8), 0);
+}
+
+#[test]
+fn look_at_cardinals() {
+	use steering::look_at;
+	use std::f64::consts::PI;
+	let from = Vec2D::new(2f64, -3f64);
+	assert_eq!(look_at(from, from + Vec2D::new(4f64, 0f64)), 0f64);
+	assert_eq!(look_at(from, from + Vec2D::new(0f64, 4f64)), PI/2f64);
+	assert_eq!(look_at(from, from + Vec2D::new(-4f64, 0f64)), PI);
+	assert_eq!(look_at(from, from + Vec2D::new(0f64, -4f64)), -PI/2f64);
+	assert_eq!(look_at(from, from), 0f64);
+}
+
+#[test]
+fn vecn_3d_dot_and_magnitude() {
+	use vecn::VecN;
+	let (a, b) = (VecN::new([1f64, -2f64, 3f64]), VecN::new([4f64, 0.5, -1f64]));
+	assert_eq!(VecN::dot_product(a, b), 0f64);
+	assert_eq!(VecN::new([1f64, 4f64, 8f64]).magnitude(), 9f64);
+	assert_eq!((a + b).z(), 2f64);
+	assert_eq!(-a * 2f64, VecN::new([-2f64, 4f64, -6f64]));
+}
+
+#[test]
+fn vecn_2d_round_trips_vec2d() {
+	use vecn::VecN;
+	let v = Vec2D::new(3, 4);
+	let n: VecN<i32, 2> = v.into();
+	assert_eq!((n.x(), n.y()), (3, 4));
+	assert_eq!(n.magnitude(), v.magnitude());
+	assert_eq!(Vec2D::from(n), v);
+}
+
+#[test]
+fn winding_disagrees_with_even_odd_on_self_intersection() {
+	use polygon::{point_in_polygon, point_in_polygon_winding, winding_number};
+	// a pentagram: the central pentagon is wound around twice
+	let star: Vec<Vec2D<f64>> = (0..5).map(|i| {
+		let angle = std::f64::consts::PI / 2f64 + i as f64 * 4f64 * std::f64::consts::PI / 5f64;
+		Vec2D::new(angle.cos(), angle.sin())
+	}).collect();
+	let centre = Vec2D::new(0f64, 0f64);
+	assert_eq!(winding_number(centre, &star), 2);
+	assert!(point_in_polygon_winding(centre, &star));
+	assert!(!point_in_polygon(centre, &star));
+
+	// a figure-eight: the two lobes are wound in opposite directions
+	let figure_eight = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(2f64, 0f64), Vec2D::new(0f64, 2f64)];
+	let (left, right) = (Vec2D::new(0.5, 1f64), Vec2D::new(1.5, 1f64));
+	assert_eq!(winding_number(left, &figure_eight).abs(), 1);
+	assert_eq!(winding_number(left, &figure_eight), -winding_number(right, &figure_eight));
+	assert!(!point_in_polygon_winding(Vec2D::new(1f64, 0.5), &figure_eight));
+}
+
+#[test]
+fn polygon_centroid_is_area_weighted() {
+	use polygon::{centroid, polygon_centroid};
+	// an L made of a 2x1 bar along the bottom plus a 1x1 square on top of its left end
+	let l_shape = [
+		Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 1f64),
+		Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 2f64), Vec2D::new(0f64, 2f64),
+	];
+	let c = polygon_centroid(&l_shape);
+	assert!((c - Vec2D::new(5f64 / 6f64, 5f64 / 6f64)).magnitude() < 1e-12);
+	assert!((centroid(&l_shape) - c).magnitude() > 0.1);
+}
+
+#[test]
+fn polygon_centroid_degenerate_falls_back() {
+	use polygon::polygon_centroid;
+	let line = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 2f64)];
+	assert_eq!(polygon_centroid(&line), Vec2D::new(1f64, 1f64));
+}
+
+#[test]
+fn square_moment_of_inertia() {
+	use polygon::polygon_moment_of_inertia;
+	let (side, mass) = (3f64, 5f64);
+	let square = [Vec2D::new(1f64, 1f64), Vec2D::new(1f64 + side, 1f64), Vec2D::new(1f64 + side, 1f64 + side), Vec2D::new(1f64, 1f64 + side)];
+	let expected = mass * side * side / 6f64;
+	assert!((polygon_moment_of_inertia(&square, mass) - expected).abs() < 1e-9);
+	// winding order does not matter
+	let reversed: Vec<_> = square.iter().rev().cloned().collect();
+	assert!((polygon_moment_of_inertia(&reversed, mass) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn off_centre_force_moves_and_spins() {
+	use rigid_body::RigidBody2D;
+	let mut body = RigidBody2D::new(Vec2D::new(0f64, 0f64), 2f64, 0.5);
+	body.apply_force(Vec2D::new(0f64, 1f64), Vec2D::new(1f64, 0f64));
+	body.integrate(0.1);
+	assert!(body.velocity.y > 0f64 && body.position.y > 0f64);
+	assert!(body.angular_velocity > 0f64 && body.rotation > 0f64);
+
+	let mut centred = RigidBody2D::new(Vec2D::new(0f64, 0f64), 2f64, 0.5);
+	centred.apply_force(Vec2D::new(0f64, 1f64), centred.position);
+	centred.integrate(0.1);
+	assert_eq!(centred.velocity, body.velocity);
+	assert_eq!(centred.angular_velocity, 0f64);
+}
+
+#[test]
+fn torque_perpendicular_and_parallel() {
+	use rigid_body::torque;
+	let arm = Vec2D::new(1f64, 1f64);
+	let force_magnitude = 4f64;
+	let perpendicular = Vec2D::new(-1f64, 1f64).to_unit() * force_magnitude;
+	assert!((torque(arm, perpendicular) - arm.magnitude() * force_magnitude).abs() < 1e-12);
+	assert!((torque(arm, -perpendicular) + arm.magnitude() * force_magnitude).abs() < 1e-12);
+	// any other angle produces less
+	assert!(torque(arm, Vec2D::new(0f64, force_magnitude)) < arm.magnitude() * force_magnitude);
+	assert_eq!(torque(arm, arm * 3f64), 0f64);
+}
+
+#[test]
+fn pure_rotation_gives_tangential_velocity() {
+	use rigid_body::velocity_at_point;
+	let (centre, point) = (Vec2D::new(1f64, 1f64), Vec2D::new(4f64, 5f64));
+	let v = velocity_at_point(Vec2D::new(0f64, 0f64), 0.5, centre, point);
+	assert!(Vec2D::dot_product(v, point - centre).abs() < 1e-12);
+	assert!((v.magnitude() - 0.5 * 5f64).abs() < 1e-12);
+	// counter-clockwise rotation moves the point anticlockwise about the centre
+	assert!(Vec2D::cross_product(point - centre, v) > 0f64);
+	assert_eq!(velocity_at_point(Vec2D::new(2f64, 3f64), 0.5, centre, centre), Vec2D::new(2f64, 3f64));
+}
+
+#[test]
+fn symmetric_rigid_body_collision() {
+	use rigid_body::{RigidBody2D, resolve_collision};
+	let mut a = RigidBody2D::new(Vec2D::new(-1f64, 0f64), 2f64, 1f64);
+	let mut b = RigidBody2D::new(Vec2D::new(1f64, 0f64), 2f64, 1f64);
+	a.velocity = Vec2D::new(3f64, 0f64);
+	b.velocity = Vec2D::new(-3f64, 0f64);
+	resolve_collision(&mut a, &mut b, Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), 1f64);
+	assert!((a.velocity - Vec2D::new(-3f64, 0f64)).magnitude() < 1e-12);
+	assert!((b.velocity - Vec2D::new(3f64, 0f64)).magnitude() < 1e-12);
+	assert_eq!((a.angular_velocity, b.angular_velocity), (0f64, 0f64));
+}
+
+#[test]
+fn off_centre_rigid_body_collision_spins() {
+	use rigid_body::{RigidBody2D, resolve_collision};
+	let mut a = RigidBody2D::new(Vec2D::new(-1f64, 0f64), 1f64, 0.5);
+	let mut b = RigidBody2D::new(Vec2D::new(1f64, 0f64), 1f64, 0.5);
+	a.velocity = Vec2D::new(2f64, 0f64);
+	// b is struck below its centre
+	resolve_collision(&mut a, &mut b, Vec2D::new(0f64, -0.5), Vec2D::new(1f64, 0f64), 0.5);
+	assert!(b.velocity.x > 0f64);
+	assert!(b.angular_velocity > 0f64);
+	assert!(a.angular_velocity < 0f64);
+	let momentum = a.velocity * a.mass + b.velocity * b.mass;
+	assert!((momentum - Vec2D::new(2f64, 0f64)).magnitude() < 1e-12);
+}
+
+#[test]
+fn viewport_culling() {
+	use camera::is_visible;
+	use shapes::AABB;
+	let viewport = AABB::new(Vec2D::new(-100f64, -50f64), Vec2D::new(100f64, 50f64));
+	assert!(is_visible(Vec2D::new(99.9, 49.9), &viewport, 0f64));
+	assert!(!is_visible(Vec2D::new(100.1, 0f64), &viewport, 0f64));
+	assert!(!is_visible(Vec2D::new(0f64, -50.1), &viewport, 0f64));
+	// within the margin band
+	assert!(is_visible(Vec2D::new(104f64, 0f64), &viewport, 5f64));
+	assert!(is_visible(Vec2D::new(-3f64, -54f64), &viewport, 5f64));
+	assert!(!is_visible(Vec2D::new(106f64, 0f64), &viewport, 5f64));
+}
+
+#[test]
+fn camera_transforms_are_inverses() {
+	use camera::Camera2D;
+	let camera = Camera2D::new(Vec2D::new(-37.5, 120f64), 0.75, Vec2D::new(1280f64, 720f64));
+	assert_eq!(camera.world_to_screen(camera.position), Vec2D::new(640f64, 360f64));
+	for &p in &[Vec2D::new(0f64, 0f64), Vec2D::new(13.25, -999f64), Vec2D::new(1e4, 3.5)] {
+		assert!((camera.screen_to_world(camera.world_to_screen(p)) - p).magnitude() < 1e-9);
+		assert!((camera.world_to_screen(camera.screen_to_world(p)) - p).magnitude() < 1e-9);
+	}
+}
+
+#[test]
+fn camera_follow_converges() {
+	use camera::Camera2D;
+	let target = Vec2D::new(250f64, -80f64);
+	let mut camera = Camera2D::new(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(800f64, 600f64));
+	let mut last = (target - camera.position).magnitude();
+	for _ in 0..200 {
+		camera.follow(target, 5f64, 1f64 / 60f64);
+		let remaining = (target - camera.position).magnitude();
+		assert!(remaining < last);
+		last = remaining;
+	}
+	assert!(last < 1e-3);
+}
+
+#[test]
+fn camera_follow_scales_with_dt() {
+	use camera::Camera2D;
+	let target = Vec2D::new(100f64, 100f64);
+	let start = Camera2D::new(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(800f64, 600f64));
+	let (mut short, mut long, mut twice) = (start, start, start);
+	short.follow(target, 3f64, 0.01);
+	long.follow(target, 3f64, 0.02);
+	twice.follow(target, 3f64, 0.01);
+	twice.follow(target, 3f64, 0.01);
+	assert!(long.position.magnitude() > short.position.magnitude());
+	assert!((long.position - twice.position).magnitude() < 1e-9);
+}
+
+#[test]
+fn screen_shake_decays_and_stays_bounded() {
+	use camera::ScreenShake;
+	let max_offset = Vec2D::new(12f64, 8f64);
+	let mut shake = ScreenShake::new(0.8, 20f64, 99);
+	shake.add_trauma(2f64);
+	assert_eq!(shake.trauma, 1f64);
+	let mut moved = false;
+	for _ in 0..120 {
+		let offset = shake.offset(1f64 / 60f64, max_offset);
+		assert!(offset.x.abs() <= max_offset.x && offset.y.abs() <= max_offset.y);
+		moved |= offset != Vec2D::new(0f64, 0f64);
+	}
+	assert!(moved);
+	assert_eq!(shake.trauma, 0f64);
+	assert_eq!(shake.offset(1f64 / 60f64, max_offset), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn full_circle_arc() {
+	use polygon::arc_points;
+	let (centre, radius) = (Vec2D::new(3f64, -2f64), 5f64);
+	let (start, end) = (0.3, 0.3 + 2f64 * std::f64::consts::PI);
+	let points = arc_points(centre, radius, start, end, 16);
+	assert_eq!(points.len(), 17);
+	for &p in &points {
+		assert!(((p - centre).magnitude() - radius).abs() < 1e-12);
+	}
+	assert!(((points[0] - centre).angle() - start).abs() < 1e-12);
+	assert!((points[16] - points[0]).magnitude() < 1e-12);
+}
+
+#[test]
+fn partial_arc_endpoints() {
+	use polygon::arc_points;
+	use std::f64::consts::PI;
+	let points = arc_points(Vec2D::new(0f64, 0f64), 1f64, PI / 4f64, 3f64 * PI / 4f64, 3);
+	assert!(((points[0]).angle() - PI / 4f64).abs() < 1e-12);
+	assert!(((points[3]).angle() - 3f64 * PI / 4f64).abs() < 1e-12);
+}
+
+#[test]
+fn regular_square_and_hexagon() {
+	use polygon::regular_polygon;
+	let square = regular_polygon(Vec2D::new(1f64, 1f64), 2f64, 4, 0f64);
+	let expected = [Vec2D::new(3f64, 1f64), Vec2D::new(1f64, 3f64), Vec2D::new(-1f64, 1f64), Vec2D::new(1f64, -1f64)];
+	assert_eq!(square.len(), 4);
+	for (&p, &e) in square.iter().zip(expected.iter()) {
+		assert!((p - e).magnitude() < 1e-12);
+	}
+	let hexagon = regular_polygon(Vec2D::new(0f64, 0f64), 1f64, 6, 0.1);
+	assert_eq!(hexagon.len(), 6);
+	for i in 0..6 {
+		assert!(((hexagon[(i + 1) % 6] - hexagon[i]).magnitude() - 1f64).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn triangulate_convex_polygon() {
+	use polygon::{regular_polygon, signed_area, triangulate};
+	let heptagon = regular_polygon(Vec2D::new(0f64, 0f64), 3f64, 7, 0f64);
+	let triangles = triangulate(&heptagon);
+	assert_eq!(triangles.len(), 5);
+	let total: f64 = triangles.iter().map(|t| signed_area(t)).sum();
+	assert!((total - signed_area(&heptagon)).abs() < 1e-9);
+}
+
+#[test]
+fn triangulate_concave_polygon() {
+	use polygon::{centroid, point_in_polygon, signed_area, triangulate};
+	// an arrow-head with a reflex vertex at (2, 1)
+	let arrow = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 1f64), Vec2D::new(4f64, 0f64), Vec2D::new(2f64, 4f64)];
+	let triangles = triangulate(&arrow);
+	assert_eq!(triangles.len(), 2);
+	let total: f64 = triangles.iter().map(|t| signed_area(t)).sum();
+	assert!((total - signed_area(&arrow)).abs() < 1e-9);
+	for t in &triangles {
+		assert!(signed_area(t) > 0f64);
+		assert!(point_in_polygon(centroid(t), &arrow));
+	}
+
+	// clockwise input works too
+	let reversed: Vec<_> = arrow.iter().rev().cloned().collect();
+	assert_eq!(triangulate(&reversed).len(), 2);
+}
+
+#[test]
+fn minkowski_sum_of_squares() {
+	use collision::minkowski_sum;
+	use polygon::signed_area;
+	let small = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(0f64, 1f64)];
+	let large = [Vec2D::new(2f64, 2f64), Vec2D::new(2f64, 4f64), Vec2D::new(0f64, 4f64), Vec2D::new(0f64, 2f64)];
+	let sum = minkowski_sum(&small, &large);
+	assert_eq!(sum, vec![Vec2D::new(0f64, 2f64), Vec2D::new(3f64, 2f64), Vec2D::new(3f64, 5f64), Vec2D::new(0f64, 5f64)]);
+	assert_eq!(signed_area(&sum), 9f64);
+}
+
+#[test]
+fn minkowski_sum_of_square_and_triangle() {
+	use collision::minkowski_sum;
+	use polygon::signed_area;
+	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+	let triangle = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64)];
+	let sum = minkowski_sum(&square, &triangle);
+	assert_eq!(sum.len(), 5);
+	// area(A + B) = area(A) + area(B) + mixed area; for this pair that's 4 + 0.5 + 4
+	assert!((signed_area(&sum) - 8.5).abs() < 1e-12);
+}
+
+#[test]
+fn gjk_overlapping_separated_touching() {
+	use collision::gjk_intersects;
+	use polygon::regular_polygon;
+	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(0f64, 1f64)];
+	let shifted = |offset: Vec2D<f64>| square.iter().map(|&p| p + offset).collect::<Vec<_>>();
+
+	assert!(gjk_intersects(&square, &shifted(Vec2D::new(0.5, 0.5))));
+	assert!(gjk_intersects(&square, &shifted(Vec2D::new(0f64, 0f64))));
+	assert!(!gjk_intersects(&square, &shifted(Vec2D::new(1.5, 0f64))));
+	assert!(!gjk_intersects(&square, &shifted(Vec2D::new(-0.5, 3f64))));
+	// touching along an edge and at a corner
+	assert!(gjk_intersects(&square, &shifted(Vec2D::new(1f64, 0f64))));
+	assert!(gjk_intersects(&square, &shifted(Vec2D::new(1f64, 1f64))));
+
+	let hexagon = regular_polygon(Vec2D::new(2.2, 0.5), 1.5, 6, 0.2);
+	assert!(gjk_intersects(&hexagon, &square));
+	let far_hexagon = regular_polygon(Vec2D::new(4f64, 4f64), 1.5, 6, 0.2);
+	assert!(!gjk_intersects(&far_hexagon, &square));
+}
+
+#[test]
+fn epa_separates_to_touching() {
+	use collision::{epa_penetration, gjk_intersects};
+	use polygon::regular_polygon;
+	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 2f64), Vec2D::new(0f64, 2f64)];
+	let cases = vec![
+		regular_polygon(Vec2D::new(2.5, 1.2), 1f64, 5, 0.3),
+		regular_polygon(Vec2D::new(1f64, 1f64), 1f64, 4, 0f64),
+		square.iter().map(|&p| p + Vec2D::new(0.25, -1.5)).collect::<Vec<_>>(),
+		// fully coincident, where GJK ends on a segment through the origin
+		square.to_vec(),
+	];
+	for other in &cases {
+		let mtv = epa_penetration(&square, other).unwrap();
+		assert!(mtv.magnitude() > 0f64);
+		let moved = |scale: f64| square.iter().map(|&p| p + mtv * scale).collect::<Vec<_>>();
+		assert!(gjk_intersects(&moved(0.99), other));
+		assert!(!gjk_intersects(&moved(1.01), other));
+	}
+	// a known depth: overlapping by 0.5 vertically
+	let mtv = epa_penetration(&square, &cases[2]).unwrap();
+	assert!((mtv - Vec2D::new(0f64, 0.5)).magnitude() < 1e-9);
+
+	let apart = square.iter().map(|&p| p + Vec2D::new(5f64, 0f64)).collect::<Vec<_>>();
+	assert_eq!(epa_penetration(&square, &apart), None);
+}
+
+#[test]
+fn quadtree_subdivides_and_queries() {
+	use quadtree::QuadTree;
+	use shapes::AABB;
+	let mut tree = QuadTree::new(AABB::new(Vec2D::new(-50f64, -50f64), Vec2D::new(50f64, 50f64)), 3);
+	let mut positions = Vec::new();
+	for i in 0..200 {
+		let p = Vec2D::new(((i * 37) % 100) as f64 - 50f64, ((i * 61) % 100) as f64 - 50f64);
+		assert!(tree.insert(p, i));
+		positions.push(p);
+	}
+	// coincident points must not recurse forever
+	for i in 200..220 {
+		assert!(tree.insert(Vec2D::new(10f64, 10f64), i));
+		positions.push(Vec2D::new(10f64, 10f64));
+	}
+	assert_eq!(tree.len(), 220);
+
+	let region = AABB::new(Vec2D::new(-20f64, -5f64), Vec2D::new(12.5, 30f64));
+	let mut found: Vec<usize> = tree.query(&region).into_iter().cloned().collect();
+	found.sort();
+	let expected: Vec<usize> = (0..positions.len()).filter(|&i| region.contains(positions[i])).collect();
+	assert!(!expected.is_empty());
+	assert_eq!(found, expected);
+}
+
+#[test]
+fn astar_routes_around_obstacle() {
+	use pathfinding::astar_grid;
+	// a 10x10 room with a wall at x = 5 spanning y in 0..8
+	let walkable = |c: Vec2D<i32>| c.x >= 0 && c.x < 10 && c.y >= 0 && c.y < 10 && !(c.x == 5 && c.y < 8);
+	let (start, goal) = (Vec2D::new(2, 2), Vec2D::new(8, 2));
+	for &diagonal in &[false, true] {
+		let path = astar_grid(start, goal, walkable, diagonal).unwrap();
+		assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+		for pair in path.windows(2) {
+			assert!(walkable(pair[1]));
+			let step = pair[1].chebyshev_distance(pair[0]);
+			assert_eq!(step, 1);
+			if !diagonal {
+				assert_eq!(pair[1].manhattan_distance(pair[0]), 1);
+			}
+		}
+		// diagonal paths may not cut the wall's corners, so they step orthogonally around its end
+		let expected_steps = if diagonal { 14 } else { 18 };
+		assert_eq!(path.len() - 1, expected_steps);
+	}
+}
+
+#[test]
+fn astar_unreachable_goal() {
+	use pathfinding::astar_grid;
+	// the goal is boxed in by walls
+	let goal = Vec2D::new(6, 6);
+	let walkable = |c: Vec2D<i32>| c.x >= 0 && c.x < 10 && c.y >= 0 && c.y < 10 && (c == goal || c.chebyshev_distance(goal) != 1);
+	assert_eq!(astar_grid(Vec2D::new(0, 0), goal, walkable, true), None);
+	assert_eq!(astar_grid(Vec2D::new(0, 0), goal, walkable, false), None);
+}
+
+#[test]
+fn flow_field_points_to_goal() {
+	use pathfinding::{astar_grid, flow_field};
+	use shapes::AABB;
+	let bounds = AABB::new(Vec2D::new(0, 0), Vec2D::new(9, 9));
+	let walkable = |c: Vec2D<i32>| !(c.x == 4 && c.y < 7);
+	let goal = Vec2D::new(8, 1);
+	let field = flow_field(&[goal], walkable, bounds);
+
+	assert!(!field.contains_key(&Vec2D::new(4, 3)));
+	assert!(!field.contains_key(&Vec2D::new(-1, 3)));
+	assert_eq!(field.len(), 100 - 7);
+	// following the field from any cell reaches the goal along a shortest path
+	for (&start, _) in field.iter() {
+		let mut cell = start;
+		let mut steps = 0;
+		while cell != goal {
+			let step = field[&cell];
+			assert_eq!(step.manhattan_distance(Vec2D::new(0, 0)), 1);
+			cell += step;
+			assert!(walkable(cell));
+			steps += 1;
+		}
+		let in_bounds = |c: Vec2D<i32>| bounds.contains(c) && walkable(c);
+		assert_eq!(steps, astar_grid(start, goal, in_bounds, false).unwrap().len() - 1);
+	}
+}
+
+#[test]
+fn flow_field_nearest_of_several_goals() {
+	use pathfinding::flow_field;
+	use shapes::AABB;
+	let field = flow_field(&[Vec2D::new(0, 0), Vec2D::new(10, 0)], |_| true, AABB::new(Vec2D::new(0, 0), Vec2D::new(10, 0)));
+	assert_eq!(field[&Vec2D::new(3, 0)], Vec2D::new(-1, 0));
+	assert_eq!(field[&Vec2D::new(7, 0)], Vec2D::new(1, 0));
+}
+
+#[test]
+fn bresenham_is_connected_and_ends_at_end() {
+	use raster::bresenham_line;
+	for &(start, end) in &[(Vec2D::new(0, 0), Vec2D::new(7, 3)), (Vec2D::new(5, 5), Vec2D::new(-2, 9)), (Vec2D::new(1, 1), Vec2D::new(1, 1)), (Vec2D::new(3, 8), Vec2D::new(3, -4))] {
+		let cells: Vec<_> = bresenham_line(start, end).collect();
+		assert_eq!((cells[0], cells[cells.len() - 1]), (start, end));
+		assert_eq!(cells.len() as i32, start.chebyshev_distance(end) + 1);
+		for pair in cells.windows(2) {
+			assert_eq!(pair[0].chebyshev_distance(pair[1]), 1);
+		}
+	}
+}
+
+#[test]
+fn line_of_sight_clear_and_blocked() {
+	use grid::has_line_of_sight;
+	let pillar = |c: Vec2D<i32>| c == Vec2D::new(5, 5);
+	assert!(has_line_of_sight(Vec2D::new(0, 0), Vec2D::new(10, 3), pillar));
+	assert!(!has_line_of_sight(Vec2D::new(0, 0), Vec2D::new(10, 10), pillar));
+	assert!(!has_line_of_sight(Vec2D::new(5, 0), Vec2D::new(5, 9), pillar));
+	// the endpoints don't block
+	assert!(has_line_of_sight(Vec2D::new(5, 5), Vec2D::new(5, 9), pillar));
+}
+
+#[test]
+fn fov_walls_cast_shadows() {
+	use grid::compute_fov;
+	let origin = Vec2D::new(0, 0);
+	// a short wall segment to the east of the origin
+	let wall = |c: Vec2D<i32>| c.x == 3 && c.y.abs() <= 1;
+	let visible = compute_fov(origin, 8, wall);
+	assert!(visible.contains(&origin));
+	for y in -1..2 {
+		assert!(visible.contains(&Vec2D::new(3, y)));
+		assert!(!visible.contains(&Vec2D::new(6, y)));
+	}
+	assert!(!visible.contains(&Vec2D::new(4, 0)));
+	// unobstructed directions are lit out to the radius, but not beyond it
+	assert!(visible.contains(&Vec2D::new(-8, 0)));
+	assert!(visible.contains(&Vec2D::new(0, 8)));
+	assert!(visible.contains(&Vec2D::new(-5, -5)));
+	assert!(!visible.contains(&Vec2D::new(-6, -6)));
+	assert!(visible.iter().all(|c| c.x * c.x + c.y * c.y <= 64));
+}
+
+#[test]
+fn fov_open_field_is_a_disc() {
+	use grid::compute_fov;
+	let radius = 6;
+	let visible = compute_fov(Vec2D::new(2, -3), radius, |_| false);
+	for x in -radius..radius + 1 {
+		for y in -radius..radius + 1 {
+			assert_eq!(visible.contains(&Vec2D::new(2 + x, -3 + y)), x * x + y * y <= radius * radius);
+		}
+	}
+}
+
+#[test]
+fn hex_pixel_round_trip() {
+	use hex::{hex_to_pixel, pixel_to_hex};
+	for q in -6..7 {
+		for r in -6..7 {
+			let hex = Vec2D::new(q, r);
+			let centre = hex_to_pixel(hex, 16f64);
+			assert_eq!(pixel_to_hex(centre, 16f64), hex);
+			// anywhere well inside the hex's incircle maps back to it too
+			assert_eq!(pixel_to_hex(centre + Vec2D::new(6f64, -7f64), 16f64), hex);
+		}
+	}
+}
+
+#[test]
+fn hex_neighbours_are_one_step_away() {
+	use hex::{hex_distance, hex_neighbors, hex_to_pixel};
+	let centre = Vec2D::new(-2, 5);
+	let neighbours = hex_neighbors(centre);
+	for (i, &n) in neighbours.iter().enumerate() {
+		assert_eq!(hex_distance(centre, n), 1);
+		for &other in &neighbours[i + 1..] {
+			assert!(n != other);
+		}
+		let spacing = (hex_to_pixel(n, 1f64) - hex_to_pixel(centre, 1f64)).magnitude();
+		assert!((spacing - 3f64.sqrt()).abs() < 1e-12);
+	}
+	assert_eq!(hex_distance(centre, centre), 0);
+	assert_eq!(hex_distance(Vec2D::new(0, 0), Vec2D::new(3, 3)), 6);
+}
+
+#[test]
+fn sdf_circle_signs() {
+	use sdf::sdf_circle;
+	let (centre, radius) = (Vec2D::new(1f64, -1f64), 3f64);
+	assert!(sdf_circle(Vec2D::new(2f64, 0f64), centre, radius) < 0f64);
+	assert!(sdf_circle(centre + Vec2D::new(0.6, 0.8) * radius, centre, radius).abs() < 1e-12);
+	assert!(sdf_circle(Vec2D::new(10f64, 0f64), centre, radius) > 0f64);
+}
+
+#[test]
+fn sdf_box_signs() {
+	use sdf::sdf_box;
+	let (centre, half_extents) = (Vec2D::new(-2f64, 3f64), Vec2D::new(1.5, 0.5));
+	assert!(sdf_box(centre + Vec2D::new(1f64, 0.25), centre, half_extents) < 0f64);
+	assert!(sdf_box(centre + Vec2D::new(1.5, 0.1), centre, half_extents).abs() < 1e-12);
+	assert!(sdf_box(centre + Vec2D::new(-1.5, -0.5), centre, half_extents).abs() < 1e-12);
+	assert!(sdf_box(centre + Vec2D::new(0f64, 0.75), centre, half_extents) > 0f64);
+	// diagonally off a corner the distance is to the corner itself
+	assert!((sdf_box(centre + Vec2D::new(4.5, 4.5), centre, half_extents) - 5f64).abs() < 1e-12);
+}
+
+#[test]
+fn sdf_segment_distances() {
+	use sdf::sdf_segment;
+	let (a, b) = (Vec2D::new(-1f64, -1f64), Vec2D::new(1f64, 1f64));
+	assert_eq!(sdf_segment(Vec2D::new(0.5, 0.5), a, b), 0f64);
+	assert!(sdf_segment(Vec2D::new(0f64, 1f64), a, b) > 0f64);
+	assert!((sdf_segment(Vec2D::new(-1f64, 1f64), a, b) - 2f64.sqrt()).abs() < 1e-12);
+	assert_eq!(sdf_segment(Vec2D::new(4f64, 5f64), a, b), 5f64);
+	// degenerate segments measure to the point
+	assert_eq!(sdf_segment(Vec2D::new(3f64, 4f64), Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64)), 5f64);
+}
+
+#[test]
+fn marching_squares_circle_contour() {
+	use contour::marching_squares;
+	use shapes::AABB;
+	let (centre, radius) = (Vec2D::new(0.3, -0.2), 3f64);
+	let bounds = AABB::new(Vec2D::new(-5f64, -5f64), Vec2D::new(5f64, 5f64));
+	let segments = marching_squares(|p| (p - centre).magnitude(), bounds, Vec2D::new(40, 40), radius);
+	assert!(segments.len() > 40);
+	for s in &segments {
+		assert!(((s.start - centre).magnitude() - radius).abs() < 0.02);
+		assert!(((s.end - centre).magnitude() - radius).abs() < 0.02);
+	}
+	let perimeter: f64 = segments.iter().map(|s| s.length()).sum();
+	assert!((perimeter - 2f64 * std::f64::consts::PI * radius).abs() < 0.1);
+}
+
+#[test]
+fn marching_squares_saddle_uses_centre() {
+	use contour::marching_squares;
+	use shapes::AABB;
+	// a single cell with high values at the bottom left and top right corners and a low centre
+	let field = |p: Vec2D<f64>| p.x * p.y;
+	let bounds = AABB::new(Vec2D::new(-1f64, -1f64), Vec2D::new(1f64, 1f64));
+	let segments = marching_squares(field, bounds, Vec2D::new(1, 1), 0.5);
+	assert_eq!(segments.len(), 2);
+	// each segment stays in the quadrant of the high corner it cuts off
+	for s in &segments {
+		assert!(s.start.x * s.end.x > 0f64 || s.start.y * s.end.y > 0f64);
+		assert!((s.start.x + s.end.x).signum() == (s.start.y + s.end.y).signum());
+	}
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_round_trip() {
+	use glam::{Vec2, DVec2};
+	let single = Vec2D::new(1.5f32, -2.25f32);
+	let converted: Vec2 = single.into();
+	assert_eq!(converted, Vec2::new(1.5, -2.25));
+	assert_eq!(Vec2D::from(converted), single);
+
+	let double = Vec2D::new(3.125f64, 7.5f64);
+	let converted: DVec2 = double.into();
+	assert_eq!(converted, DVec2::new(3.125, 7.5));
+	assert_eq!(Vec2D::from(converted), double);
+}
+
+#[test]
+fn f32_magnitude_and_angle() {
+	let v = Vec2D::new(3.0f32, 4.0f32);
+	let magnitude: f32 = v.magnitude_f32();
+	assert_eq!(magnitude, 5.0f32);
+	assert!((Vec2D::new(-1f32, -1f32).angle_f32() + 3f32 * std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+}
+
+#[test]
+fn one_euro_filter_constant_input_converges() {
+	use filter::OneEuroFilter2D;
+	let mut filter = OneEuroFilter2D::new(1f64, 0.5, 1f64);
+	let target = Vec2D::new(4f64, -2f64);
+	let mut out = Vec2D::default();
+	for _ in 0..100 {
+		out = filter.filter(target, 1f64 / 60f64);
+	}
+	assert_eq!(out, target);
+}
+
+#[test]
+fn one_euro_filter_smooths_then_tracks_step() {
+	use filter::OneEuroFilter2D;
+	let mut filter = OneEuroFilter2D::new(1f64, 0.01, 1f64);
+	let dt = 1f64 / 60f64;
+	for _ in 0..10 {
+		filter.filter(Vec2D::new(0f64, 0f64), dt);
+	}
+	let step = Vec2D::new(10f64, 0f64);
+	let first = filter.filter(step, dt);
+	assert!(first.x > 0f64 && first.x < 5f64);
+	let mut out = first;
+	for _ in 0..300 {
+		out = filter.filter(step, dt);
+	}
+	assert!((out - step).magnitude() < 0.01);
+}
+
+#[test]
+fn debug_rounded_precisions() {
+	let v = Vec2D::new(1.23456, -7.65432);
+	assert_eq!(v.debug_rounded(0), "(1, -8)");
+	assert_eq!(v.debug_rounded(1), "(1.2, -7.7)");
+	assert_eq!(v.debug_rounded(4), "(1.2346, -7.6543)");
+	assert_eq!(Vec2D::new(2, 5).debug_rounded(2), "(2.00, 5.00)");
+}
+
+#[test]
+fn velocity_verlet_harmonic_oscillator() {
+	use integrate::velocity_verlet;
+	// unit mass on a spring of stiffness k: x(t) = cos(ωt), v(t) = -ω sin(ωt)
+	let k = 4f64;
+	let omega = k.sqrt();
+	let accel = |p: Vec2D<f64>| p * -k;
+	let energy = |p: Vec2D<f64>, v: Vec2D<f64>| 0.5 * Vec2D::dot_product(v, v) + 0.5 * k * Vec2D::dot_product(p, p);
+	let (mut position, mut velocity) = (Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 0f64));
+	let initial_energy = energy(position, velocity);
+	let dt = 0.01;
+	for step in 1..=1000 {
+		let next = velocity_verlet(position, velocity, accel, dt);
+		position = next.0;
+		velocity = next.1;
+		assert!((energy(position, velocity) - initial_energy).abs() < 1e-3);
+		let t = step as f64 * dt;
+		assert!((position.x - (omega * t).cos()).abs() < 1e-2);
+	}
+	assert!((velocity.x + omega * (omega * 10f64).sin()).abs() < 2e-2);
+}
+
+#[test]
+fn rk4_circular_motion_beats_euler() {
+	use integrate::rk4_step;
+	// rotating about the origin at one radian per second
+	let derivative = |p: Vec2D<f64>| p.perp();
+	let dt = 0.1;
+	let (mut rk4, mut euler) = (Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 0f64));
+	for _ in 0..100 {
+		rk4 = rk4_step(rk4, derivative, dt);
+		euler += derivative(euler) * dt;
+	}
+	let exact = Vec2D::new(10f64.cos(), 10f64.sin());
+	let rk4_error = (rk4 - exact).magnitude();
+	let euler_error = (euler - exact).magnitude();
+	assert!(rk4_error < 1e-4);
+	assert!(rk4_error * 1000f64 < euler_error);
+}
+
+#[test]
+fn triangle_area_right_and_degenerate() {
+	use polygon::triangle_area;
+	assert_eq!(triangle_area(Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 4f64), Vec2D::new(5f64, 1f64)), 6f64);
+	assert_eq!(triangle_area(Vec2D::new(0, 0), Vec2D::new(1, 1), Vec2D::new(3, 3)), 0f64);
+}
+
+#[test]
+fn smallest_enclosing_circle_contains_all_points() {
+	use geometry::smallest_enclosing_circle;
+	let points: Vec<Vec2D<f64>> = (0..50).map(|i| {
+		let t = i as f64;
+		Vec2D::new((t * 1.7).sin() * 5f64 + (t * 0.3).cos(), (t * 2.3).cos() * 3f64 - 1f64)
+	}).collect();
+	let circle = smallest_enclosing_circle(&points);
+	for &p in &points {
+		assert!((p - circle.center).magnitude() <= circle.radius + 1e-9);
+	}
+	// the circle is determined by points on its boundary, at least two of them
+	let on_boundary = points.iter().filter(|&&p| ((p - circle.center).magnitude() - circle.radius).abs() < 1e-9).count();
+	assert!(on_boundary >= 2);
+}
+
+#[test]
+fn smallest_enclosing_circle_tight_for_triangle() {
+	use geometry::smallest_enclosing_circle;
+	// an acute triangle is enclosed by its circumcircle, centred at (0, 0) with radius 5
+	let points = [Vec2D::new(-3f64, -4f64), Vec2D::new(3f64, -4f64), Vec2D::new(0f64, 5f64)];
+	let circle = smallest_enclosing_circle(&points);
+	assert!((circle.center - Vec2D::new(0f64, 0f64)).magnitude() < 1e-9);
+	assert!((circle.radius - 5f64).abs() < 1e-9);
+	assert_eq!(smallest_enclosing_circle(&[]).radius, 0f64);
+}
+
+#[test]
+fn closest_pair_matches_brute_force() {
+	use geometry::closest_pair;
+	use noise::lattice_value;
+	for seed in 0..5 {
+		let points: Vec<Vec2D<f64>> = (0..60).map(|i| Vec2D::new(lattice_value(i, 0, seed) * 100f64, lattice_value(i, 1, seed) * 100f64)).collect();
+		let mut brute = f64::INFINITY;
+		for i in 0..points.len() {
+			for j in i + 1..points.len() {
+				brute = brute.min((points[i] - points[j]).magnitude());
+			}
+		}
+		let (i, j, d) = closest_pair(&points).unwrap();
+		assert!(i < j);
+		assert_eq!(d, brute);
+		assert_eq!((points[i] - points[j]).magnitude(), d);
+	}
+}
+
+#[test]
+fn delaunay_empty_circumcircles() {
+	use geometry::delaunay;
+	use noise::lattice_value;
+	use polygon::triangle_area;
+	// the corners of the unit square, a regular (cocircular) grid, some scattered points and a duplicate
+	let mut points = vec![Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(0f64, 1f64)];
+	for i in 1..4 {
+		for j in 1..4 {
+			points.push(Vec2D::new(i as f64 * 0.25, j as f64 * 0.25));
+		}
+	}
+	for i in 0..20 {
+		points.push(Vec2D::new(lattice_value(i, 0, 3), lattice_value(i, 1, 3)));
+	}
+	points.push(points[5]);
+
+	let triangles = delaunay(&points);
+	assert!(!triangles.is_empty());
+	let mut area = 0f64;
+	for t in &triangles {
+		let (a, b, c) = (points[t[0]], points[t[1]], points[t[2]]);
+		assert!(Vec2D::cross_product(b - a, c - a) > 0f64);
+		area += triangle_area(a, b, c);
+		// circumcircle of abc
+		let (ab, ac) = (b - a, c - a);
+		let d = 2f64 * Vec2D::cross_product(ab, ac);
+		let offset = Vec2D::new(ac.y * Vec2D::dot_product(ab, ab) - ab.y * Vec2D::dot_product(ac, ac), ab.x * Vec2D::dot_product(ac, ac) - ac.x * Vec2D::dot_product(ab, ab)) / d;
+		let (center, radius) = (a + offset, offset.magnitude());
+		for &p in &points {
+			assert!((p - center).magnitude() >= radius - 1e-9);
+		}
+	}
+	// the triangles exactly tile the convex hull (the unit square)
+	assert!((area - 1f64).abs() < 1e-9);
+
+	assert!(delaunay(&[Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 2f64), Vec2D::new(3f64, 3f64)]).is_empty());
+}
+
+#[test]
+fn in_circle_inside_and_outside() {
+	use geometry::in_circle;
+	// the circumcircle of this triangle is centred on (2, 1) with radius 5
+	let (a, b, c) = (Vec2D::new(-1f64, -3f64), Vec2D::new(7f64, 1f64), Vec2D::new(2f64, 6f64));
+	assert!(in_circle(a, b, c, Vec2D::new(2f64, 1f64)));
+	assert!(in_circle(c, b, a, Vec2D::new(5f64, 4f64)));
+	assert!(!in_circle(a, b, c, Vec2D::new(8f64, 5f64)));
+	assert!(!in_circle(c, b, a, Vec2D::new(-4f64, 1f64)));
+	assert!(!in_circle(a, a, b, Vec2D::new(2f64, 1f64)));
+}
+
+#[test]
+fn circumcircle_equilateral_and_collinear() {
+	use geometry::circumcircle;
+	use polygon::centroid;
+	let triangle = [Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 1f64), Vec2D::new(2f64, 1f64 + 3f64.sqrt())];
+	let circle = circumcircle(triangle[0], triangle[1], triangle[2]).unwrap();
+	assert!((circle.center - centroid(&triangle)).magnitude() < 1e-12);
+	assert!((circle.radius - 2f64 / 3f64.sqrt()).abs() < 1e-12);
+	assert_eq!(circumcircle(Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 1f64), Vec2D::new(-4f64, -2f64)), None);
+	assert_eq!(circumcircle(Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 1f64), Vec2D::new(5f64, 0f64)), None);
+}
+
+#[test]
+fn voronoi_two_points_split_by_bisector() {
+	use geometry::voronoi_cells;
+	use polygon::{point_in_polygon, signed_area};
+	let (a, b) = (Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 3f64));
+	let cells = voronoi_cells(&[a, b]);
+	assert!(point_in_polygon(a, &cells[0]));
+	assert!(point_in_polygon(b, &cells[1]));
+	assert!(!point_in_polygon(b, &cells[0]));
+	// every vertex is on the site's side of (or on) the bisector x + y = 4
+	assert!(cells[0].iter().all(|p| p.x + p.y <= 4f64 + 1e-9));
+	assert!(cells[1].iter().all(|p| p.x + p.y >= 4f64 - 1e-9));
+	// the bisector halves the symmetric bounding box
+	assert!((signed_area(&cells[0]) - signed_area(&cells[1])).abs() < 1e-9);
+	assert!(signed_area(&cells[0]) > 0f64);
+}
+
+#[test]
+fn voronoi_cells_contain_their_sites() {
+	use geometry::voronoi_cells;
+	use noise::lattice_value;
+	use polygon::point_in_polygon;
+	let points: Vec<Vec2D<f64>> = (0..25).map(|i| Vec2D::new(lattice_value(i, 0, 9) * 10f64, lattice_value(i, 1, 9) * 10f64)).collect();
+	let cells = voronoi_cells(&points);
+	for (i, cell) in cells.iter().enumerate() {
+		assert!(point_in_polygon(points[i], cell));
+		// every vertex of the cell is at least as close to its own site as to any other
+		for &v in cell {
+			let own = (v - points[i]).magnitude();
+			assert!(points.iter().all(|&p| (v - p).magnitude() >= own - 1e-9));
+		}
+	}
+}
+
+#[test]
+fn poisson_disk_points_spaced_and_bounded() {
+	use sampling::poisson_disk_sample;
+	use shapes::AABB;
+	let bounds = AABB::new(Vec2D::new(-3f64, 2f64), Vec2D::new(12f64, 9f64));
+	let min_distance = 0.75;
+	let points = poisson_disk_sample(bounds, min_distance, 42);
+	// a maximal sampling covers the area densely
+	assert!(points.len() > 100);
+	for (i, &a) in points.iter().enumerate() {
+		assert!(bounds.contains(a));
+		for &b in &points[i + 1..] {
+			assert!((a - b).magnitude() >= min_distance);
+		}
+	}
+	assert_eq!(points, poisson_disk_sample(bounds, min_distance, 42));
+	assert_ne!(points, poisson_disk_sample(bounds, min_distance, 43));
+	assert!(poisson_disk_sample(bounds, 0f64, 42).is_empty());
+}
+
+#[test]
+fn rotate_quarter_turn() {
+	let r = Vec2D::new(1, 0).rotate(std::f64::consts::FRAC_PI_2);
+	assert!((r - Vec2D::new(0f64, 1f64)).magnitude() < f64::EPSILON);
+	let mut v = Vec2D::new(1f64, 0f64);
+	v.rotate_assign(std::f64::consts::FRAC_PI_2);
+	assert_eq!(v, r);
+}
+
+#[test]
+fn rotate_preserves_magnitude() {
+	let v = Vec2D::new(3, -4);
+	for i in 0..16 {
+		assert!((v.rotate(i as f64 * 0.7).magnitude() - 5f64).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn rotate_full_turn_and_zero() {
+	let v = Vec2D::new(-2.5, 7.25);
+	assert!((v.rotate(2f64 * std::f64::consts::PI) - v).magnitude() < 1e-12);
+	assert_eq!(Vec2D::new(0, 0).rotate(1.234), Vec2D::new(0f64, 0f64));
+	assert_eq!(Vec2D::new(0f64, 0f64).rotate(-3f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn from_polar_round_trip() {
+	let vectors = [
+		Vec2D::new(1f64, 0f64), Vec2D::new(0f64, -1f64), Vec2D::new(0.6, 0.8), Vec2D::new(-0.28, 0.96),
+		Vec2D::new(3f64, 4f64), Vec2D::new(-120.5, 33.0), Vec2D::new(0.003, -0.004), Vec2D::new(-7.25e6, -1.5e6)
+	];
+	for &v in &vectors {
+		// the error is relative to the vector's length
+		let tolerance = f64::EPSILON * v.magnitude();
+		let back = Vec2D::from_polar(v.magnitude(), v.angle());
+		assert!((back.x - v.x).abs() <= tolerance, "{:?} -> {:?}", v, back);
+		assert!((back.y - v.y).abs() <= tolerance, "{:?} -> {:?}", v, back);
+	}
+	assert_eq!(Vec2D::from_polar(0f64, 2f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn simplify_polyline_collapses_straight_line() {
+	use polyline::simplify_polyline;
+	let line: Vec<Vec2D<f64>> = (0..20).map(|i| Vec2D::new(i as f64, if i % 2 == 0 { 0.01 } else { -0.01 })).collect();
+	assert_eq!(simplify_polyline(&line, 0.1), vec![line[0], line[19]]);
+}
+
+#[test]
+fn simplify_polyline_keeps_corners() {
+	use polyline::simplify_polyline;
+	// an L shape with jitter along both legs
+	let mut line = Vec::new();
+	for i in 0..10 {
+		line.push(Vec2D::new(i as f64, 0.02 * (i % 3) as f64));
+	}
+	for i in 0..10 {
+		line.push(Vec2D::new(10f64 + 0.02 * (i % 2) as f64, i as f64));
+	}
+	line.push(Vec2D::new(10f64, 10f64));
+	let simplified = simplify_polyline(&line, 0.1);
+	assert_eq!(simplified, vec![line[0], line[10], line[20]]);
+	assert_eq!(simplify_polyline(&line[..2], 0.1), line[..2].to_vec());
+}
+
+#[test]
+fn map_u8_to_f64() {
+	let v = Vec2D::new(6u8, 8u8).map(|c| c as f64);
+	assert_eq!(v, Vec2D::new(6f64, 8f64));
+	assert_eq!(v.magnitude(), 10f64);
+	assert_eq!(Vec2D::new(6u8, 8u8).cast::<f64>().to_unit(), Vec2D::new(0.6, 0.8));
+	assert_eq!(Vec2D::<f64>::from(Vec2D::new(-2, 7)), Vec2D::new(-2f64, 7f64));
+	assert_eq!(Vec2D::new(-2, -7).abs(), Vec2D::new(2, 7));
+}
+
+#[test]
+fn chaikin_smooth_square() {
+	use polyline::chaikin_smooth;
+	let square = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 4f64), Vec2D::new(0f64, 4f64)];
+	assert_eq!(chaikin_smooth(&square, 1, true), vec![
+		Vec2D::new(1f64, 0f64), Vec2D::new(3f64, 0f64),
+		Vec2D::new(4f64, 1f64), Vec2D::new(4f64, 3f64),
+		Vec2D::new(3f64, 4f64), Vec2D::new(1f64, 4f64),
+		Vec2D::new(0f64, 3f64), Vec2D::new(0f64, 1f64)
+	]);
+	assert_eq!(chaikin_smooth(&square, 3, true).len(), 32);
+	assert_eq!(chaikin_smooth(&square, 0, true), square.to_vec());
+}
+
+#[test]
+fn offset_polygon_insets_square() {
+	use polygon::offset_polygon;
+	let square = [Vec2D::new(1f64, 1f64), Vec2D::new(7f64, 1f64), Vec2D::new(7f64, 7f64), Vec2D::new(1f64, 7f64)];
+	let inset = offset_polygon(&square, -2f64);
+	let expected = [Vec2D::new(3f64, 3f64), Vec2D::new(5f64, 3f64), Vec2D::new(5f64, 5f64), Vec2D::new(3f64, 5f64)];
+	for (a, b) in inset.iter().zip(expected.iter()) {
+		assert!((*a - *b).magnitude() < 1e-12);
+	}
+	// clockwise input insets the same way
+	let mut clockwise = square.to_vec();
+	clockwise.reverse();
+	let mut inset_clockwise = offset_polygon(&clockwise, -2f64);
+	inset_clockwise.reverse();
+	for (a, b) in inset_clockwise.iter().zip(expected.iter()) {
+		assert!((*a - *b).magnitude() < 1e-12);
+	}
+}
+
+#[test]
+fn offset_polygon_reflex_corner() {
+	use polygon::offset_polygon;
+	// an L shape whose reflex corner at (1, 1) moves inward diagonally when grown
+	let l_shape = [Vec2D::new(0f64, 0f64), Vec2D::new(2f64, 0f64), Vec2D::new(2f64, 1f64), Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 2f64), Vec2D::new(0f64, 2f64)];
+	let grown = offset_polygon(&l_shape, 0.5);
+	assert!((grown[3] - Vec2D::new(1.5, 1.5)).magnitude() < 1e-12);
+	assert!((grown[0] - Vec2D::new(-0.5, -0.5)).magnitude() < 1e-12);
+}
+
+#[test]
+fn rotating_calipers_match_brute_force() {
+	use polygon::{convex_hull, polygon_diameter, polygon_min_width};
+	use noise::lattice_value;
+	for seed in 0..5 {
+		let points: Vec<Vec2D<f64>> = (0..30).map(|i| Vec2D::new(lattice_value(i, 0, seed) * 10f64, lattice_value(i, 1, seed) * 4f64)).collect();
+		let hull = convex_hull(&points);
+		assert!(hull.len() >= 3);
+
+		let mut diameter = 0f64;
+		for &a in &hull {
+			for &b in &hull {
+				diameter = diameter.max((a - b).magnitude());
+			}
+		}
+		assert!((polygon_diameter(&hull) - diameter).abs() < 1e-9);
+
+		// the width is the smallest, over every edge direction, of the hull's extent along that edge's normal
+		let width = (0..hull.len()).map(|i| {
+			let normal = (hull[(i + 1) % hull.len()] - hull[i]).perp().to_unit();
+			let projections: Vec<f64> = hull.iter().map(|&p| Vec2D::dot_product(p, normal)).collect();
+			projections.iter().cloned().fold(f64::NEG_INFINITY, f64::max) - projections.iter().cloned().fold(f64::INFINITY, f64::min)
+		}).fold(f64::INFINITY, f64::min);
+		assert!((polygon_min_width(&hull) - width).abs() < 1e-9);
+
+		let mut clockwise = hull.clone();
+		clockwise.reverse();
+		assert!((polygon_min_width(&clockwise) - width).abs() < 1e-9);
+	}
+}
+
+#[test]
+fn convex_hull_contains_points() {
+	use polygon::{convex_hull, orientation};
+	use noise::lattice_value;
+	let points: Vec<Vec2D<f64>> = (0..40).map(|i| Vec2D::new(lattice_value(i, 0, 1), lattice_value(i, 1, 1))).collect();
+	let hull = convex_hull(&points);
+	for i in 0..hull.len() {
+		let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+		assert!(points.iter().all(|&p| orientation(a, b, p) >= 0f64));
+	}
+	assert_eq!(convex_hull(&[Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 1f64)]), vec![Vec2D::new(1f64, 1f64)]);
+}
+
+#[test]
+fn min_area_rect_rotated_rectangle() {
+	use polygon::min_area_rect;
+	let (center, angle) = (Vec2D::new(3f64, -2f64), std::f64::consts::PI / 6f64);
+	let corners = [Vec2D::new(-2f64, -1f64), Vec2D::new(2f64, -1f64), Vec2D::new(2f64, 1f64), Vec2D::new(-2f64, 1f64)];
+	let mut points: Vec<Vec2D<f64>> = corners.iter().map(|&c| center + c.rotate(angle)).collect();
+	// interior points don't affect the result
+	points.push(center + Vec2D::new(0.5, 0.25).rotate(angle));
+	let (found_center, half_extents, rotation) = min_area_rect(&points);
+	assert!((found_center - center).magnitude() < 1e-9);
+	assert!((half_extents - Vec2D::new(2f64, 1f64)).magnitude() < 1e-9);
+	assert!((rotation - angle).abs() < 1e-9);
+
+	// a square rotated by a further quarter turn is the same square
+	let square: Vec<Vec2D<f64>> = corners.iter().map(|&c| Vec2D::new(c.x / 2f64, c.y).rotate(angle + std::f64::consts::FRAC_PI_2)).collect();
+	let (_, half_extents, rotation) = min_area_rect(&square);
+	assert!((half_extents - Vec2D::new(1f64, 1f64)).magnitude() < 1e-9);
+	assert!((rotation - angle).abs() < 1e-9);
+}
+
+#[test]
+fn dedup_points_merges_close_points() {
+	use geometry::dedup_points;
+	// the near-coincident pair straddles a cell boundary
+	let points = [Vec2D::new(0.999, 2f64), Vec2D::new(5f64, 5f64), Vec2D::new(1.001, 2f64), Vec2D::new(1f64, 3f64), Vec2D::new(-4f64, 0f64)];
+	assert_eq!(dedup_points(&points, 0.01), vec![points[0], points[1], points[3], points[4]]);
+	assert_eq!(dedup_points(&points, 0f64), points.to_vec());
+	assert_eq!(dedup_points(&[points[1], points[1]], 0f64), vec![points[1]]);
+}
+
+#[test]
+fn isometric_round_trip() {
+	use coords::{cart_to_iso, iso_to_cart};
+	assert_eq!(cart_to_iso(Vec2D::new(1f64, 0f64)), Vec2D::new(1f64, 0.5));
+	assert_eq!(cart_to_iso(Vec2D::new(1f64, 1f64)), Vec2D::new(0f64, 1f64));
+	for &v in &[Vec2D::new(3.5, -2f64), Vec2D::new(-7f64, 0.25), Vec2D::new(0f64, 0f64)] {
+		assert!((iso_to_cart(cart_to_iso(v)) - v).magnitude() < 1e-12);
+		assert!((cart_to_iso(iso_to_cart(v)) - v).magnitude() < 1e-12);
+	}
+}
+
+#[test]
+fn kdtree_nearest_matches_brute_force() {
+	use kdtree::KdTree2D;
+	use noise::lattice_value;
+	let points: Vec<Vec2D<f64>> = (0..200).map(|i| Vec2D::new(lattice_value(i, 0, 4) * 50f64, lattice_value(i, 1, 4) * 50f64)).collect();
+	let tree = KdTree2D::new(&points);
+	assert_eq!(tree.len(), 200);
+	for q in 0..50 {
+		let query = Vec2D::new(lattice_value(q, 2, 4) * 60f64 - 5f64, lattice_value(q, 3, 4) * 60f64 - 5f64);
+		let brute = points.iter().map(|&p| (p - query).magnitude()).fold(f64::INFINITY, f64::min);
+		let (index, distance) = tree.nearest(query);
+		assert_eq!(distance, brute);
+		assert_eq!((points[index] - query).magnitude(), distance);
+	}
+}
+
+#[test]
+fn kdtree_radius_query_exact() {
+	use kdtree::KdTree2D;
+	use noise::lattice_value;
+	let points: Vec<Vec2D<f64>> = (0..150).map(|i| Vec2D::new(lattice_value(i, 0, 8) * 20f64, lattice_value(i, 1, 8) * 20f64)).collect();
+	let tree = KdTree2D::new(&points);
+	let (query, radius) = (Vec2D::new(10f64, 8f64), 4.5);
+	let mut found = tree.within_radius(query, radius);
+	found.sort();
+	let expected: Vec<usize> = (0..points.len()).filter(|&i| (points[i] - query).magnitude() <= radius).collect();
+	assert!(!expected.is_empty());
+	assert_eq!(found, expected);
+	assert!(KdTree2D::new(&[]).within_radius(query, radius).is_empty());
+}
+
+#[test]
+fn flock_step_lone_boid() {
+	use steering::{flock_step, Boid, FlockParams};
+	let params = FlockParams{perception_radius: 5f64, separation_radius: 1f64, separation_weight: 1.5, alignment_weight: 1f64, cohesion_weight: 1f64};
+	let boid = Boid::new(Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 0f64));
+	assert_eq!(flock_step(&boid, &[], &params), Vec2D::new(0f64, 0f64));
+	// itself and a boid out of range don't count
+	let far = Boid::new(Vec2D::new(50f64, 1f64), Vec2D::new(0f64, 3f64));
+	assert_eq!(flock_step(&boid, &[boid, far], &params), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn flock_step_close_boids_separate() {
+	use steering::{flock_step, Boid, FlockParams};
+	let params = FlockParams{perception_radius: 5f64, separation_radius: 1f64, separation_weight: 1f64, alignment_weight: 1f64, cohesion_weight: 0.1};
+	let a = Boid::new(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 1f64));
+	let b = Boid::new(Vec2D::new(0.5, 0f64), Vec2D::new(0f64, 1f64));
+	let flock = [a, b];
+	let force_a = flock_step(&a, &flock, &params);
+	let force_b = flock_step(&b, &flock, &params);
+	assert!(force_a.x < 0f64);
+	assert!(force_b.x > 0f64);
+	// they already share a heading, so nothing pushes them along it
+	assert_eq!(force_a.y, 0f64);
+}
+
+#[test]
+fn tangent_points_external_and_internal() {
+	use geometry::tangent_points;
+	use shapes::Circle;
+	let circle = Circle::new(Vec2D::new(1f64, 2f64), 3f64);
+	let from = Vec2D::new(1f64, 7f64);
+	let (a, b) = tangent_points(&circle, from).unwrap();
+	assert!((a - b).magnitude() > 1f64);
+	for &t in &[a, b] {
+		// on the circle, and the radius meets the tangent line at a right angle
+		assert!(((t - circle.center).magnitude() - 3f64).abs() < 1e-12);
+		assert!(Vec2D::dot_product(t - circle.center, from - t).abs() < 1e-9);
+	}
+	// a 3-4-5 triangle: the tangent points are 4 away from `from`
+	assert!(((a - from).magnitude() - 4f64).abs() < 1e-12);
+	assert_eq!(tangent_points(&circle, Vec2D::new(2f64, 3f64)), None);
+	assert_eq!(tangent_points(&circle, circle.center), None);
+}
+
+#[test]
+fn circle_circle_intersection_cases() {
+	use geometry::circle_circle_intersections;
+	use shapes::Circle;
+	let a = Circle::new(Vec2D::new(1f64, 1f64), 2f64);
+	let crossing = circle_circle_intersections(&a, &Circle::new(Vec2D::new(3f64, 2f64), 1.5));
+	assert_eq!(crossing.len(), 2);
+	for &p in &crossing {
+		assert!(((p - a.center).magnitude() - 2f64).abs() < 1e-12);
+		assert!(((p - Vec2D::new(3f64, 2f64)).magnitude() - 1.5).abs() < 1e-12);
+	}
+	// externally and internally tangent
+	assert_eq!(circle_circle_intersections(&a, &Circle::new(Vec2D::new(4f64, 1f64), 1f64)), vec![Vec2D::new(3f64, 1f64)]);
+	assert_eq!(circle_circle_intersections(&a, &Circle::new(Vec2D::new(1f64, 2f64), 1f64)), vec![Vec2D::new(1f64, 3f64)]);
+	// apart, nested and identical
+	assert!(circle_circle_intersections(&a, &Circle::new(Vec2D::new(6f64, 1f64), 1f64)).is_empty());
+	assert!(circle_circle_intersections(&a, &Circle::new(Vec2D::new(1.5, 1f64), 0.5)).is_empty());
+	assert!(circle_circle_intersections(&a, &a).is_empty());
+}
+
+#[test]
+fn trilaterate_recovers_point() {
+	use geometry::trilaterate;
+	let target = Vec2D::new(-3.25, 7.5);
+	let anchors = [Vec2D::new(1f64, 1f64), Vec2D::new(12f64, -4f64), Vec2D::new(-6f64, 10f64)];
+	let range = |i: usize| (target - anchors[i]).magnitude();
+	let found = trilaterate(anchors[0], range(0), anchors[1], range(1), anchors[2], range(2)).unwrap();
+	assert!((found - target).magnitude() < 1e-9);
+	// inconsistent ranges, and collinear anchors
+	assert_eq!(trilaterate(anchors[0], range(0) + 1f64, anchors[1], range(1), anchors[2], range(2)), None);
+	assert_eq!(trilaterate(Vec2D::new(0f64, 0f64), 1f64, Vec2D::new(1f64, 1f64), 1f64, Vec2D::new(2f64, 2f64), 1f64), None);
+}
+
+#[test]
+fn total_turning_closed_and_straight() {
+	use polyline::total_turning;
+	use polygon::regular_polygon;
+	let mut hexagon = regular_polygon(Vec2D::new(2f64, 1f64), 3f64, 6, 0.3);
+	hexagon.push(hexagon[0]);
+	assert!((total_turning(&hexagon) - 2f64 * std::f64::consts::PI).abs() < 1e-12);
+	hexagon.reverse();
+	assert!((total_turning(&hexagon) + 2f64 * std::f64::consts::PI).abs() < 1e-12);
+
+	let line = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 3f64)];
+	assert_eq!(total_turning(&line), 0f64);
+	// a zig-zag turns both ways, cancelling out
+	let zig_zag = [Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 0f64), Vec2D::new(3f64, 1f64)];
+	assert!(total_turning(&zig_zag).abs() < 1e-12);
+}
+
+#[test]
+fn toroidal_delta_wraps_around_edges() {
+	use toroidal_delta;
+	let size = Vec2D::new(100f64, 60f64);
+	// going left past x = 0 and down past y = 0 is shorter than crossing the world
+	let delta = toroidal_delta(Vec2D::new(3f64, 4f64), Vec2D::new(97f64, 50f64), size);
+	assert_eq!(delta, Vec2D::new(-6f64, -14f64));
+	assert!(delta.magnitude() < (Vec2D::new(97f64, 50f64) - Vec2D::new(3f64, 4f64)).magnitude());
+	// nearby points are unaffected
+	assert_eq!(toroidal_delta(Vec2D::new(40f64, 30f64), Vec2D::new(45f64, 20f64), size), Vec2D::new(5f64, -10f64));
+}
+
+#[test]
+fn normalize_stable_tiny_and_huge() {
+	let tiny = Vec2D::new(1e-200, 1e-200);
+	assert!(!tiny.to_unit().x.is_finite());
+	let unit = tiny.normalize_stable();
+	assert!((unit.magnitude() - 1f64).abs() < 1e-15);
+	assert!((unit.x - 0.5f64.sqrt()).abs() < 1e-15);
+	let huge = Vec2D::new(-3e300, 4e300);
+	assert!(huge.to_unit().magnitude() != 1f64);
+	assert_eq!(huge.normalize_stable(), Vec2D::new(-0.6, 0.8));
+	assert_eq!(Vec2D::new(0, 0).normalize_stable(), Vec2D::new(0f64, 0f64));
+	assert_eq!(Vec2D::new(0, -7).normalize_stable(), Vec2D::new(0f64, -1f64));
+}
+
+#[test]
+fn cell_id_round_trips() {
+	let extremes = [i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX - 1, i32::MAX];
+	for &x in &extremes {
+		for &y in &extremes {
+			let cell = Vec2D::new(x, y);
+			assert_eq!(Vec2D::from_cell_id(cell.to_cell_id()), cell);
+		}
+	}
+	assert_ne!(Vec2D::new(3, -7).to_cell_id(), Vec2D::new(-7, 3).to_cell_id());
+	assert_eq!(Vec2D::new(i32::MIN, i32::MIN).to_cell_id(), u64::MAX);
+}
+
+#[test]
+fn morton_round_trip_and_locality() {
+	for &(x, y) in &[(0u32, 0u32), (1, 2), (12345, 678), (u32::MAX, 0), (0, u32::MAX), (u32::MAX, u32::MAX)] {
+		let cell = Vec2D::new(x, y);
+		assert_eq!(Vec2D::morton_decode(cell.morton_encode()), cell);
+	}
+	assert_eq!(Vec2D::new(u32::MAX, u32::MAX).morton_encode(), u64::MAX);
+	// the cells of an aligned 4x4 block get a contiguous range of 16 codes
+	let base = Vec2D::new(40u32, 24u32).morton_encode();
+	let mut block: Vec<u64> = (0..4).flat_map(|dx| (0..4).map(move |dy| Vec2D::new(40 + dx, 24 + dy).morton_encode())).collect();
+	block.sort();
+	assert_eq!(block, (base..base + 16).collect::<Vec<u64>>());
+}
+
+#[test]
+fn hilbert_round_trip_and_adjacency() {
+	let order = 4;
+	let mut previous: Option<Vec2D<u32>> = None;
+	for index in 0..(1u64 << (2 * order)) {
+		let cell = Vec2D::hilbert_decode(index, order);
+		assert_eq!(cell.hilbert_encode(order), index);
+		if let Some(p) = previous {
+			let step = Vec2D::new(cell.x as i32 - p.x as i32, cell.y as i32 - p.y as i32);
+			assert_eq!(step.manhattan_distance(Vec2D::new(0, 0)), 1);
+		}
+		previous = Some(cell);
+	}
+	let far = Vec2D::new(u32::MAX, 7u32);
+	assert_eq!(Vec2D::hilbert_decode(far.hilbert_encode(32), 32), far);
+}
+
+#[test]
+fn grid_cells_row_major() {
+	use grid::grid_cells;
+	use shapes::AABB;
+	let bounds = AABB::new(Vec2D::new(-2, 3), Vec2D::new(5, 7));
+	let cells: Vec<Vec2D<i32>> = grid_cells(&bounds).collect();
+	assert_eq!(cells.len(), 8 * 5);
+	assert_eq!(cells[0], Vec2D::new(-2, 3));
+	assert_eq!(cells[1], Vec2D::new(-1, 3));
+	assert_eq!(cells[8], Vec2D::new(-2, 4));
+	assert_eq!(*cells.last().unwrap(), Vec2D::new(5, 7));
+	// the same cells `AABB::contains` accepts
+	assert!(cells.iter().all(|&cell| bounds.contains(cell)));
+	assert_eq!(grid_cells(&AABB::new(Vec2D::new(1, 1), Vec2D::new(1, 5))).count(), 5);
+	assert_eq!(grid_cells(&AABB::new(Vec2D::new(1, 1), Vec2D::new(0, 5))).count(), 0);
+}
+
+#[test]
+fn neighbor_offsets() {
+	let cell = Vec2D::new(3, -2);
+	let offsets4: Vec<Vec2D<i32>> = cell.neighbors4().iter().map(|&n| n - cell).collect();
+	assert_eq!(offsets4, vec![Vec2D::new(0, -1), Vec2D::new(1, 0), Vec2D::new(0, 1), Vec2D::new(-1, 0)]);
+	let offsets8: Vec<Vec2D<i32>> = cell.neighbors8().iter().map(|&n| n - cell).collect();
+	assert_eq!(offsets8, vec![
+		Vec2D::new(0, -1), Vec2D::new(1, -1), Vec2D::new(1, 0), Vec2D::new(1, 1),
+		Vec2D::new(0, 1), Vec2D::new(-1, 1), Vec2D::new(-1, 0), Vec2D::new(-1, -1)
+	]);
+	assert!(cell.neighbors4().iter().all(|n| cell.neighbors8().contains(n)));
+}
+
+#[test]
+fn flood_fill_stays_behind_walls() {
+	use grid::flood_fill;
+	// a 10x6 room split by a wall at x = 4, with the only gap being a diagonal one at (4, 2)/(5, 3)
+	let open = |c: Vec2D<i32>| {
+		let in_room = c.x >= 0 && c.x < 10 && c.y >= 0 && c.y < 6;
+		let wall = c.x == 4 || (c.x == 5 && c.y != 3);
+		in_room && (!wall || c == Vec2D::new(4, 2))
+	};
+	let left = flood_fill(Vec2D::new(1, 1), open, false);
+	assert_eq!(left.len(), 4 * 6 + 1);
+	assert!(left.iter().all(|c| c.x <= 4));
+	// diagonal moves slip through the gap
+	let both = flood_fill(Vec2D::new(1, 1), open, true);
+	assert!(both.contains(&Vec2D::new(5, 3)));
+	assert!(both.contains(&Vec2D::new(9, 5)));
+	assert_eq!(both.len(), 4 * 6 + 1 + 1 + 4 * 6);
+	assert!(flood_fill(Vec2D::new(4, 0), open, true).is_empty());
+}
+
+#[test]
+fn cellular_step_conway_blinker_and_block() {
+	use std::collections::HashSet;
+	use grid::cellular_step;
+	use shapes::AABB;
+	let bounds = AABB::new(Vec2D::new(0, 0), Vec2D::new(9, 9));
+	let set = |cells: &[(i32, i32)]| cells.iter().map(|&(x, y)| Vec2D::new(x, y)).collect::<HashSet<_>>();
+	let horizontal = set(&[(3, 4), (4, 4), (5, 4)]);
+	let vertical = set(&[(4, 3), (4, 4), (4, 5)]);
+	let next = cellular_step(&horizontal, &bounds, &[2, 3], &[3]);
+	assert_eq!(next, vertical);
+	assert_eq!(cellular_step(&next, &bounds, &[2, 3], &[3]), horizontal);
+
+	let block = set(&[(1, 1), (2, 1), (1, 2), (2, 2)]);
+	assert_eq!(cellular_step(&block, &bounds, &[2, 3], &[3]), block);
+	// `bounds.max` is inside the grid, so a block in that corner is still stable
+	let corner = set(&[(8, 8), (9, 8), (8, 9), (9, 9)]);
+	assert_eq!(cellular_step(&corner, &bounds, &[2, 3], &[3]), corner);
+}
+
+#[test]
+fn center_of_mass_nearer_heavier() {
+	use polygon::center_of_mass;
+	let (light, heavy) = (Vec2D::new(-2f64, 1f64), Vec2D::new(6f64, 5f64));
+	let com = center_of_mass(&[(light, 1f64), (heavy, 9f64)]);
+	assert!((com - Vec2D::new(5.2, 4.6)).magnitude() < 1e-12);
+	assert!((com - heavy).magnitude() < (com - light).magnitude());
+	assert_eq!(center_of_mass(&[]), Vec2D::new(0f64, 0f64));
+	assert_eq!(center_of_mass(&[(light, 0f64), (heavy, 0f64)]), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn principal_axis_of_elongated_cluster() {
+	use polygon::principal_axis;
+	use noise::lattice_value;
+	// a long thin cloud along the direction at 30 degrees
+	let direction = Vec2D::from_polar(1f64, std::f64::consts::PI / 6f64);
+	let points: Vec<Vec2D<f64>> = (0..100).map(|i| {
+		let along = lattice_value(i, 0, 2) * 20f64 - 10f64;
+		let across = lattice_value(i, 1, 2) * 2f64 - 1f64;
+		Vec2D::new(4f64, -3f64) + direction * along + direction.perp() * across
+	}).collect();
+	let (major, minor) = principal_axis(&points);
+	assert!((major.magnitude() - 1f64).abs() < 1e-12);
+	assert!(Vec2D::dot_product(major, direction).abs() > 0.999);
+	assert!(Vec2D::dot_product(minor, direction).abs() < 0.05);
+	assert_eq!(principal_axis(&[]), (Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64)));
+}
+
+#[test]
+fn smooth_damp_converges_without_overshoot() {
+	use anim::smooth_damp;
+	let target = Vec2D::new(5f64, -3f64);
+	let (mut position, mut velocity) = (Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64));
+	let mut previous_distance = (target - position).magnitude();
+	for _ in 0..600 {
+		position = smooth_damp(position, target, &mut velocity, 0.3, 1f64 / 60f64);
+		let distance = (target - position).magnitude();
+		assert!(distance <= previous_distance);
+		// always on the starting side of the target
+		assert!(Vec2D::dot_product(target - position, target) >= 0f64);
+		previous_distance = distance;
+	}
+	assert!((position - target).magnitude() < 1e-6);
+	assert!(velocity.magnitude() < 1e-5);
+}
+
+#[test]
+fn smooth_damp_tracks_moving_target() {
+	use anim::smooth_damp;
+	let target_velocity = Vec2D::new(2f64, 1f64);
+	let dt = 1f64 / 60f64;
+	let (mut position, mut velocity) = (Vec2D::new(0f64, 0f64), Vec2D::new(0f64, 0f64));
+	let mut lags = Vec::new();
+	for step in 1..=1200 {
+		let target = target_velocity * (step as f64 * dt);
+		position = smooth_damp(position, target, &mut velocity, 0.25, dt);
+		lags.push((target - position).magnitude());
+	}
+	// settles into following at the target's speed with a steady lag
+	assert!((velocity - target_velocity).magnitude() < 1e-2);
+	let settled = &lags[lags.len() - 100..];
+	assert!(settled.iter().all(|&lag| (lag - settled[0]).abs() < 1e-3 && lag < 1f64));
+}
+
+#[test]
+fn closest_point_on_polygon_outside_and_inside() {
+	use polygon::closest_point_on_polygon;
+	let triangle = [Vec2D::new(0f64, 0f64), Vec2D::new(6f64, 0f64), Vec2D::new(0f64, 6f64)];
+	// beyond the hypotenuse, and past a vertex
+	assert!((closest_point_on_polygon(Vec2D::new(4f64, 4f64), &triangle) - Vec2D::new(3f64, 3f64)).magnitude() < 1e-12);
+	assert_eq!(closest_point_on_polygon(Vec2D::new(-2f64, -1f64), &triangle), Vec2D::new(0f64, 0f64));
+	// inside, nearest to the bottom edge
+	let inside = Vec2D::new(2f64, 0.5);
+	let snapped = closest_point_on_polygon(inside, &triangle);
+	assert_ne!(snapped, inside);
+	assert_eq!(snapped, Vec2D::new(2f64, 0f64));
+}
+
+#[test]
+fn ray_circle_cases() {
+	use raycast::ray_circle_intersection;
+	use shapes::Circle;
+	let circle = Circle::new(Vec2D::new(5f64, 5f64), 2f64);
+	let origin = Vec2D::new(5f64, -5f64);
+	// direct hit on the near side
+	assert_eq!(ray_circle_intersection(origin, Vec2D::new(0f64, 1f64), &circle), Some(8f64));
+	// a non-unit direction gives the ray parameter rather than the distance
+	assert_eq!(ray_circle_intersection(origin, Vec2D::new(0f64, 2f64), &circle), Some(4f64));
+	// grazing the side
+	assert_eq!(ray_circle_intersection(Vec2D::new(3f64, -5f64), Vec2D::new(0f64, 1f64), &circle), Some(10f64));
+	// passing by
+	assert_eq!(ray_circle_intersection(Vec2D::new(2f64, -5f64), Vec2D::new(0f64, 1f64), &circle), None);
+	// from inside, out through the far side
+	assert_eq!(ray_circle_intersection(Vec2D::new(5f64, 4f64), Vec2D::new(0f64, 1f64), &circle), Some(3f64));
+}
+
+#[test]
+fn ray_polygon_front_face_and_miss() {
+	use raycast::ray_polygon_intersection;
+	let square = [Vec2D::new(-1f64, 3f64), Vec2D::new(1f64, 3f64), Vec2D::new(1f64, 5f64), Vec2D::new(-1f64, 5f64)];
+	let (t, normal) = ray_polygon_intersection(Vec2D::new(0.5, 0f64), Vec2D::new(0f64, 1f64), &square).unwrap();
+	assert_eq!(t, 3f64);
+	assert_eq!(normal, Vec2D::new(0f64, -1f64));
+	// at an angle, striking the left face first
+	let (t, normal) = ray_polygon_intersection(Vec2D::new(-3f64, 2f64), Vec2D::new(1f64, 1f64), &square).unwrap();
+	assert_eq!(t, 2f64);
+	assert_eq!(normal, Vec2D::new(-1f64, 0f64));
+	assert_eq!(ray_polygon_intersection(Vec2D::new(3f64, 0f64), Vec2D::new(0f64, 1f64), &square), None);
+	assert_eq!(ray_polygon_intersection(Vec2D::new(0f64, 0f64), Vec2D::new(0f64, -1f64), &square), None);
+}
+
+#[test]
+fn sweep_circle_catches_thin_wall() {
+	use raycast::sweep_circle_polygon;
+	// a wall far thinner than the distance travelled in one step
+	let wall = [Vec2D::new(10f64, -5f64), Vec2D::new(10.1, -5f64), Vec2D::new(10.1, 5f64), Vec2D::new(10f64, 5f64)];
+	let (start, radius, velocity) = (Vec2D::new(0f64, 0f64), 0.5, Vec2D::new(40f64, 0f64));
+	let toi = sweep_circle_polygon(start, radius, velocity, &wall).unwrap();
+	assert!((toi - 9.5 / 40f64).abs() < 1e-12);
+	// clipping the wall's corner with the circle's edge
+	let toi = sweep_circle_polygon(Vec2D::new(0f64, 5.3), radius, velocity, &wall).unwrap();
+	let contact = Vec2D::new(0f64, 5.3) + velocity * toi;
+	assert!(((contact - Vec2D::new(10f64, 5f64)).magnitude() - radius).abs() < 1e-9);
+	// passing above it, stopping short, and starting in contact
+	assert_eq!(sweep_circle_polygon(Vec2D::new(0f64, 6f64), radius, velocity, &wall), None);
+	assert_eq!(sweep_circle_polygon(start, radius, Vec2D::new(9f64, 0f64), &wall), None);
+	assert_eq!(sweep_circle_polygon(Vec2D::new(9.6, 0f64), radius, velocity, &wall), Some(0f64));
+}
+
+#[test]
+fn angular_velocity_quarter_turn() {
+	use physics::angular_velocity;
+	let w = angular_velocity(Vec2D::new(0f64, 2f64), Vec2D::new(-1f64, 0f64), 1f64);
+	assert!((w - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+	// 270 degrees counter-clockwise is shorter as 90 clockwise
+	let w = angular_velocity(Vec2D::new(1f64, 0f64), Vec2D::from_polar(1f64, 1.5 * std::f64::consts::PI), 2f64);
+	assert!((w + std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+	assert_eq!(angular_velocity(Vec2D::new(1f64, 0f64), Vec2D::new(0f64, 1f64), 0f64), 0f64);
+}
+
+#[test]
+fn drag_decays_towards_zero() {
+	use physics::apply_drag;
+	let mut v = Vec2D::new(-6f64, 8f64);
+	let mut previous = v.magnitude();
+	for _ in 0..200 {
+		v = apply_drag(v, 0.8, 0.05);
+		assert!(v.magnitude() < previous && v.magnitude() > 0f64);
+		previous = v.magnitude();
+	}
+	assert!(previous < 1e-2);
+	// the direction is unchanged
+	assert!((v.to_unit() - Vec2D::new(-0.6, 0.8)).magnitude() < 1e-12);
+	// one long step matches many short ones
+	let long = apply_drag(Vec2D::new(-6f64, 8f64), 0.8, 10f64);
+	assert!((long - v).magnitude() < 1e-12);
+}
+
+#[test]
+fn friction_stops_without_reversing() {
+	use physics::apply_friction;
+	let mut v = Vec2D::new(3f64, -4f64);
+	for _ in 0..10 {
+		v = apply_friction(v, 2f64, 0.3);
+		assert!(v.x >= 0f64 && v.y <= 0f64);
+	}
+	assert_eq!(v, Vec2D::new(0f64, 0f64));
+	let slowed = apply_friction(Vec2D::new(3f64, -4f64), 2f64, 1f64);
+	assert!((slowed - Vec2D::new(1.8, -2.4)).magnitude() < 1e-12);
+}
+
+#[test]
+fn apply_impulse_knockback() {
+	use physics::apply_impulse;
+	let velocity = Vec2D::new(3f64, 0f64);
+	let knockback = Vec2D::new(-20f64, 10f64);
+	let hit = apply_impulse(velocity, knockback, 4f64);
+	assert_eq!(hit - velocity, Vec2D::new(-5f64, 2.5));
+	assert_eq!(apply_impulse(velocity, knockback, 0f64), velocity);
+}
+
+#[test]
+fn predict_trajectory_parabola() {
+	use ballistics::predict_trajectory;
+	let (start, speed, g) = (Vec2D::new(1f64, 20f64), 4f64, 9.8);
+	let path = predict_trajectory(start, Vec2D::new(speed, 0f64), Vec2D::new(0f64, -g), 10, 0.1);
+	assert_eq!(path.len(), 11);
+	assert_eq!(path[0], start);
+	for p in &path {
+		// y = y0 - g x^2 / (2 v^2), with x measured from the launch point
+		let x = p.x - start.x;
+		assert!((p.y - (start.y - g * x * x / (2f64 * speed * speed))).abs() < 1e-9);
+	}
+	assert!((path[10] - Vec2D::new(5f64, 20f64 - 4.9)).magnitude() < 1e-9);
+}
+
+#[test]
+fn launch_velocity_two_arcs_and_out_of_range() {
+	use ballistics::{launch_velocity, predict_trajectory};
+	let (start, target, speed, g) = (Vec2D::new(2f64, 1f64), Vec2D::new(-18f64, 4f64), 20f64, 9.8);
+	let (low, high) = launch_velocity(start, target, speed, g).unwrap();
+	assert!(low.y < high.y);
+	for &v in &[low, high] {
+		assert!((v.magnitude() - speed).abs() < 1e-9);
+		// following the arc, the projectile passes through the target when it has covered the horizontal distance
+		let flight_time = (target.x - start.x) / v.x;
+		let arrival = predict_trajectory(start, v, Vec2D::new(0f64, -g), 1, flight_time)[1];
+		assert!((arrival - target).magnitude() < 1e-9);
+	}
+	assert_eq!(launch_velocity(start, Vec2D::new(100f64, 1f64), speed, g), None);
+	assert_eq!(launch_velocity(start, Vec2D::new(2f64, 30f64), speed, g), None);
+}
+
+#[test]
+fn wrap_within_reappears_opposite() {
+	use shapes::AABB;
+	let bounds = AABB::new(Vec2D::new(-50f64, -20f64), Vec2D::new(50f64, 20f64));
+	let wrapped = Vec2D::new(52.5, 3f64).wrap_within(&bounds);
+	assert!((wrapped - Vec2D::new(-47.5, 3f64)).magnitude() < 1e-12);
+	assert_eq!(Vec2D::new(10f64, -21f64).wrap_within(&bounds), Vec2D::new(10f64, 19f64));
+	assert_eq!(Vec2D::new(1f64, 2f64).wrap_within(&bounds), Vec2D::new(1f64, 2f64));
+	// several widths away still lands in the box
+	assert!((Vec2D::new(-349f64, 0f64).wrap_within(&bounds) - Vec2D::new(-49f64, 0f64)).magnitude() < 1e-9);
+	// a zero-height box pins y to its min instead of dividing by zero
+	let flat = AABB::new(Vec2D::new(-50f64, 5f64), Vec2D::new(50f64, 5f64));
+	assert_eq!(Vec2D::new(60f64, -3f64).wrap_within(&flat), Vec2D::new(-40f64, 5f64));
+}
+
+#[test]
+fn should_collide_requires_both_masks() {
+	use collision::should_collide;
+	let (walls, players, bullets) = (0b001, 0b010, 0b100);
+	// players hit walls and bullets; bullets hit walls and players; walls accept everything
+	let (wall_mask, player_mask, bullet_mask) = (0b111, walls | bullets, walls | players);
+	assert!(should_collide(players, player_mask, bullets, bullet_mask));
+	assert!(should_collide(bullets, bullet_mask, walls, wall_mask));
+	// bullets ignore each other
+	assert!(!should_collide(bullets, bullet_mask, bullets, bullet_mask));
+	// one side accepting isn't enough, in either order
+	assert!(!should_collide(players, player_mask, walls, bullets));
+	assert!(!should_collide(walls, bullets, players, player_mask));
+}
+
+#[test]
+fn contact_manifold_overlapping_boxes() {
+	use collision::contact_manifold;
+	let a = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 2f64), Vec2D::new(0f64, 2f64)];
+	// resting across a's top, sunk 0.5 into it and overhanging its right side
+	let b = [Vec2D::new(3f64, 1.5), Vec2D::new(3f64, 3.5), Vec2D::new(6f64, 3.5), Vec2D::new(6f64, 1.5)];
+	let manifold = contact_manifold(&a, &b).unwrap();
+	assert_eq!(manifold.normal, Vec2D::new(0f64, 1f64));
+	assert_eq!(manifold.depth, 0.5);
+	let mut points = manifold.points.clone();
+	points.sort_by(|p, q| p.x.partial_cmp(&q.x).unwrap());
+	assert_eq!(points, vec![Vec2D::new(3f64, 1.5), Vec2D::new(4f64, 1.5)]);
+
+	// swapping the shapes flips the normal
+	let swapped = contact_manifold(&b, &a).unwrap();
+	assert_eq!(swapped.normal, Vec2D::new(0f64, -1f64));
+	assert_eq!(swapped.depth, 0.5);
+	assert_eq!(swapped.points.len(), 2);
+
+	let apart = [Vec2D::new(5f64, 0f64), Vec2D::new(6f64, 0f64), Vec2D::new(6f64, 1f64), Vec2D::new(5f64, 1f64)];
+	assert_eq!(contact_manifold(&a, &apart), None);
+}
+
+#[test]
+fn contact_manifold_corner_on_side_plane() {
+	use collision::contact_manifold;
+	let square = |x: f64, y: f64| [Vec2D::new(x, y), Vec2D::new(x + 1f64, y), Vec2D::new(x + 1f64, y + 1f64), Vec2D::new(x, y + 1f64)];
+	let a = square(0f64, 0f64);
+	// squares meeting corner to corner, where the incident edge ends exactly on a side plane of the reference edge
+	for &(corner, offset) in &[(Vec2D::new(0f64, 0f64), Vec2D::new(-1f64, -1f64)), (Vec2D::new(1f64, 0f64), Vec2D::new(1f64, -1f64)),
+		(Vec2D::new(1f64, 1f64), Vec2D::new(1f64, 1f64)), (Vec2D::new(0f64, 1f64), Vec2D::new(-1f64, 1f64))] {
+		let b = square(offset.x, offset.y);
+		for manifold in [contact_manifold(&a, &b), contact_manifold(&b, &a)].iter() {
+			let manifold = manifold.as_ref().unwrap();
+			assert_eq!(manifold.depth, 0f64);
+			assert_eq!(manifold.points, vec![corner]);
+		}
+	}
+}
+
+#[test]
+fn solve_contacts_stacked_boxes_come_to_rest() {
+	use rigid_body::{RigidBody2D, solve_contacts};
+	use collision::contact_manifold;
+	let half_extents = [Vec2D::new(5f64, 0.5), Vec2D::new(0.5, 0.5), Vec2D::new(0.5, 0.5)];
+	let box_at = |center: Vec2D<f64>, half: Vec2D<f64>| vec![
+		center - half, Vec2D::new(center.x + half.x, center.y - half.y), center + half, Vec2D::new(center.x - half.x, center.y + half.y)
+	];
+	let mut bodies = [
+		RigidBody2D::new(Vec2D::new(0f64, -0.5), 0f64, 0f64),
+		// unrotatable, as the shapes below are built axis-aligned
+		RigidBody2D::new(Vec2D::new(0f64, 0.7), 1f64, 0f64),
+		RigidBody2D::new(Vec2D::new(0f64, 2f64), 1f64, 0f64)
+	];
+	let dt = 1f64 / 60f64;
+	for _ in 0..300 {
+		for body in bodies.iter_mut().skip(1) {
+			body.velocity.y -= 10f64 * dt;
+		}
+		let mut manifolds = Vec::new();
+		for i in 0..bodies.len() {
+			for j in (i + 1)..bodies.len() {
+				let shape_i = box_at(bodies[i].position, half_extents[i]);
+				let shape_j = box_at(bodies[j].position, half_extents[j]);
+				if let Some(manifold) = contact_manifold(&shape_i, &shape_j) {
+					manifolds.push(manifold.with_bodies(i, j));
+				}
+			}
+		}
+		solve_contacts(&mut bodies, &manifolds, 10);
+		for body in bodies.iter_mut() {
+			body.integrate(dt);
+		}
+	}
+	// each box rests on the one below, overlapping by no more than a small slop
+	let (lower, upper) = (bodies[1].position.y, bodies[2].position.y);
+	assert!(lower > 0.5 - 0.03 && lower <= 0.5 + 1e-9, "{}", lower);
+	assert!(upper - lower > 1f64 - 0.03 && upper - lower <= 1f64 + 1e-9, "{}", upper - lower);
+	// well below the 1/6 per step that gravity adds
+	assert!(bodies[1].velocity.magnitude() < 1e-2 && bodies[2].velocity.magnitude() < 1e-2);
+	assert_eq!(bodies[0].position, Vec2D::new(0f64, -0.5));
+}
+
+#[test]
+fn distance_joint_settles_at_rest_length() {
+	use rigid_body::RigidBody2D;
+	use joint::DistanceJoint;
+	let mut bodies = [RigidBody2D::new(Vec2D::new(0f64, 0f64), 1f64, 1f64), RigidBody2D::new(Vec2D::new(3f64, 1f64), 2f64, 1f64)];
+	bodies[1].velocity = Vec2D::new(0f64, 1f64);
+	let joint = DistanceJoint::new(0, 1, Vec2D::new(0.5, 0f64), Vec2D::new(-0.5, 0f64), 2f64);
+	let dt = 1f64 / 60f64;
+	for _ in 0..600 {
+		joint.solve(&mut bodies, dt);
+		for body in bodies.iter_mut() {
+			body.integrate(dt);
+		}
+	}
+	let point_a = bodies[0].position + joint.anchor_a.rotate(bodies[0].rotation);
+	let point_b = bodies[1].position + joint.anchor_b.rotate(bodies[1].rotation);
+	assert!(((point_b - point_a).magnitude() - 2f64).abs() < 1e-3, "{}", (point_b - point_a).magnitude());
+}
+
+#[test]
+fn pin_joint_holds_anchors_together() {
+	use rigid_body::RigidBody2D;
+	use joint::PinJoint;
+	let mut bodies = [RigidBody2D::new(Vec2D::new(0f64, 0f64), 0f64, 0f64), RigidBody2D::new(Vec2D::new(3f64, 0f64), 1f64, 1f64)];
+	let joint = PinJoint::new(0, 1, Vec2D::new(1f64, 0f64), Vec2D::new(-1f64, 0f64));
+	let dt = 1f64 / 60f64;
+	for _ in 0..600 {
+		// a pendulum hanging from the fixed first body under gravity
+		bodies[1].velocity.y -= 10f64 * dt;
+		joint.solve(&mut bodies, dt);
+		for body in bodies.iter_mut() {
+			body.integrate(dt);
+		}
+	}
+	let point_a = bodies[0].position + joint.anchor_a.rotate(bodies[0].rotation);
+	let point_b = bodies[1].position + joint.anchor_b.rotate(bodies[1].rotation);
+	assert!((point_b - point_a).magnitude() < 0.05, "{:?} {:?}", point_a, point_b);
+	assert_eq!(bodies[0].position, Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn sweep_and_prune_matches_brute_force() {
+	use shapes::AABB;
+	use broadphase::sweep_and_prune;
+	let boxes: Vec<AABB<f64>> = (0..40).map(|i| {
+		let min = Vec2D::new(::noise::lattice_value(i, 0, 7) * 20f64, ::noise::lattice_value(i, 1, 7) * 20f64);
+		let size = Vec2D::new(::noise::lattice_value(i, 2, 7) * 4f64, ::noise::lattice_value(i, 3, 7) * 4f64);
+		AABB::new(min, min + size)
+	}).collect();
+	let mut expected = Vec::new();
+	for i in 0..boxes.len() {
+		for j in (i + 1)..boxes.len() {
+			if boxes[i].intersects(&boxes[j]) {
+				expected.push((i, j));
+			}
+		}
+	}
+	let pairs = sweep_and_prune(&boxes);
+	assert!(!expected.is_empty() && expected.len() < boxes.len() * (boxes.len() - 1) / 2);
+	assert_eq!(pairs, expected);
+
+	// overlapping on x alone isn't enough
+	let stacked = [AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64)), AABB::new(Vec2D::new(0f64, 2f64), Vec2D::new(1f64, 3f64))];
+	assert!(sweep_and_prune(&stacked).is_empty());
+}
+
+#[test]
+fn sort_points_deterministic_with_nan_and_duplicates() {
+	use geometry::sort_points_deterministic;
+	let points = [
+		Vec2D::new(1f64, f64::NAN), Vec2D::new(f64::NAN, 0f64), Vec2D::new(1f64, 2f64), Vec2D::new(-0f64, 3f64),
+		Vec2D::new(1f64, 2f64), Vec2D::new(0f64, 3f64), Vec2D::new(1f64, -1f64), Vec2D::new(f64::NAN, -5f64)
+	];
+	let bits = |points: &[Vec2D<f64>]| points.iter().map(|p| (p.x.to_bits(), p.y.to_bits())).collect::<Vec<_>>();
+	let mut forward = points;
+	sort_points_deterministic(&mut forward);
+	let mut reversed = points;
+	reversed.reverse();
+	sort_points_deterministic(&mut reversed);
+	assert_eq!(bits(&forward), bits(&reversed));
+
+	assert_eq!(forward[0].x.to_bits(), (-0f64).to_bits());
+	assert_eq!(forward[1].x.to_bits(), 0f64.to_bits());
+	assert_eq!(&forward[2..4], &[Vec2D::new(1f64, -1f64), Vec2D::new(1f64, 2f64)]);
+	assert_eq!(forward[4], Vec2D::new(1f64, 2f64));
+	assert!(forward[5].x == 1f64 && forward[5].y.is_nan());
+	assert!(forward[6].x.is_nan() && forward[6].y == -5f64);
+	assert!(forward[7].x.is_nan() && forward[7].y == 0f64);
+}
+
+#[test]
+fn turns_match_radians_and_degrees() {
+	let quarter = Vec2D::from_turns(0.25);
+	assert!((quarter - Vec2D::new(0f64, 1f64)).magnitude() < 1e-12);
+	assert!((quarter - Vec2D::from_polar(1f64, 90f64.to_radians())).magnitude() < 1e-12);
+	assert!((quarter.angle_turns() - 0.25).abs() < 1e-12);
+	assert!((Vec2D::new(0f64, 1f64).angle_turns() * 360f64 - 90f64).abs() < 1e-12);
+	assert!((Vec2D::from_turns(-0.125).angle_turns() + 0.125).abs() < 1e-12);
+	// whole turns come back around
+	assert!((Vec2D::from_turns(1.25) - quarter).magnitude() < 1e-12);
+}
+
+#[test]
+fn clamp_scalar_below_within_and_above() {
+	assert_eq!(Vec2D::new(-3f64, -7f64).clamp_scalar(-1f64, 1f64), Vec2D::new(-1f64, -1f64));
+	assert_eq!(Vec2D::new(0.25, -0.5).clamp_scalar(-1f64, 1f64), Vec2D::new(0.25, -0.5));
+	assert_eq!(Vec2D::new(4f64, 1.5).clamp_scalar(-1f64, 1f64), Vec2D::new(1f64, 1f64));
+	// each axis is clamped on its own
+	assert_eq!(Vec2D::new(-2f64, 2f64).clamp_scalar(-1f64, 1f64), Vec2D::new(-1f64, 1f64));
+	assert_eq!(Vec2D::new(7, -9).clamp_scalar(0, 5), Vec2D::new(5, 0));
+	// the bounds themselves are within the range
+	assert_eq!(Vec2D::new(-1f64, 1f64).clamp_scalar(-1f64, 1f64), Vec2D::new(-1f64, 1f64));
+}
+
+#[test]
+fn remap_unit_range_to_hundreds() {
+	let (from_min, from_max) = (Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 1f64));
+	let (to_min, to_max) = (Vec2D::new(-100f64, -100f64), Vec2D::new(100f64, 100f64));
+	assert_eq!(Vec2D::new(0f64, 1f64).remap(from_min, from_max, to_min, to_max), Vec2D::new(-100f64, 100f64));
+	assert_eq!(Vec2D::new(0.5, 0.25).remap(from_min, from_max, to_min, to_max), Vec2D::new(0f64, -50f64));
+	assert_eq!(Vec2D::new(1.5, -0.5).remap(from_min, from_max, to_min, to_max), Vec2D::new(200f64, -200f64));
+	// a zero-width input axis maps to the start of its output range rather than NaN
+	let flat = Vec2D::new(0.5, 3f64).remap(from_min, Vec2D::new(1f64, 0f64), to_min, to_max);
+	assert_eq!(flat, Vec2D::new(0f64, -100f64));
+}
+
+#[test]
+fn screen_ndc_round_trip() {
+	use coords::{screen_to_ndc, ndc_to_screen};
+	let screen = Vec2D::new(1920f64, 1080f64);
+	assert_eq!(screen_to_ndc(Vec2D::new(960f64, 540f64), screen), Vec2D::new(0f64, 0f64));
+	// the bottom-right pixel corner is at the bottom-right of NDC space
+	assert_eq!(screen_to_ndc(screen, screen), Vec2D::new(1f64, -1f64));
+	for &pixel in [Vec2D::new(0f64, 0f64), Vec2D::new(123.5, 1000f64), Vec2D::new(1919f64, 7f64), Vec2D::new(-20f64, 2000f64)].iter() {
+		assert!((ndc_to_screen(screen_to_ndc(pixel, screen), screen) - pixel).magnitude() < 1e-9);
+	}
+	let ndc = Vec2D::new(-0.3, 0.8);
+	assert!((screen_to_ndc(ndc_to_screen(ndc, screen), screen) - ndc).magnitude() < 1e-12);
+}
+
+#[test]
+fn bezier_constant_speed_sampling() {
+	use curves::{cubic_bezier, bezier_arc_length_lut, bezier_point_at_distance};
+	let (p0, p1, p2, p3) = (Vec2D::new(0f64, 0f64), Vec2D::new(1f64, 6f64), Vec2D::new(2f64, 0f64), Vec2D::new(9f64, 1f64));
+	let lut = bezier_arc_length_lut(p0, p1, p2, p3, 512);
+	let total = lut[lut.len() - 1].1;
+	let steps = 20;
+	let points: Vec<Vec2D<f64>> = (0..=steps).map(|i| bezier_point_at_distance(p0, p1, p2, p3, &lut, total * i as f64 / steps as f64)).collect();
+	assert!((points[0] - p0).magnitude() < 1e-12 && (points[steps] - p3).magnitude() < 1e-9);
+	let gaps: Vec<f64> = points.windows(2).map(|w| (w[1] - w[0]).magnitude()).collect();
+	let expected = total / steps as f64;
+	for &gap in gaps.iter() {
+		// chords are a touch shorter than the arcs they cut across
+		assert!(gap <= expected + 1e-3 && gap > expected * 0.95, "{} vs {}", gap, expected);
+	}
+
+	// whereas stepping t evenly doesn't keep an even spacing on this curve
+	let naive: Vec<f64> = (0..steps).map(|i| {
+		let (t0, t1) = (i as f64 / steps as f64, (i + 1) as f64 / steps as f64);
+		(cubic_bezier(p0, p1, p2, p3, t1) - cubic_bezier(p0, p1, p2, p3, t0)).magnitude()
+	}).collect();
+	assert!(naive.iter().any(|&gap| (gap - expected).abs() > expected * 0.2));
+
+	// distances beyond the ends are clamped
+	assert_eq!(bezier_point_at_distance(p0, p1, p2, p3, &lut, -5f64), p0);
+	assert!((bezier_point_at_distance(p0, p1, p2, p3, &lut, total * 2f64) - p3).magnitude() < 1e-9);
+}
+
+#[test]
+fn polygon_union_overlapping_and_disjoint_squares() {
+	use clip::polygon_union;
+	use polygon::signed_area;
+	let square = |min: Vec2D<f64>, size: f64| vec![
+		min, Vec2D::new(min.x + size, min.y), Vec2D::new(min.x + size, min.y + size), Vec2D::new(min.x, min.y + size)
+	];
+	let a = square(Vec2D::new(0f64, 0f64), 2f64);
+
+	// overlapping corners make a single eight-sided outline, including both crossings
+	let mut b = square(Vec2D::new(1f64, 1f64), 2f64);
+	b.reverse();
+	let union = polygon_union(&a, &b);
+	assert_eq!(union.len(), 1);
+	assert_eq!(union[0].len(), 8);
+	assert_eq!(signed_area(&union[0]), 7f64);
+	for &corner in [Vec2D::new(0f64, 0f64), Vec2D::new(3f64, 3f64), Vec2D::new(2f64, 1f64), Vec2D::new(1f64, 2f64)].iter() {
+		assert!(union[0].contains(&corner));
+	}
+	// the overlapped corners are gone
+	assert!(!union[0].contains(&Vec2D::new(2f64, 2f64)) && !union[0].contains(&Vec2D::new(1f64, 1f64)));
+
+	// a bar poking out either side of a square makes a plus-like outline
+	let bar = vec![Vec2D::new(-1f64, 0.5), Vec2D::new(3f64, 0.5), Vec2D::new(3f64, 1.5), Vec2D::new(-1f64, 1.5)];
+	let plus = polygon_union(&a, &bar);
+	assert_eq!(plus.len(), 1);
+	assert_eq!(plus[0].len(), 12);
+	assert_eq!(signed_area(&plus[0]), 6f64);
+
+	let disjoint = polygon_union(&a, &square(Vec2D::new(5f64, 5f64), 1f64));
+	assert_eq!(disjoint.len(), 2);
+	assert_eq!((signed_area(&disjoint[0]), signed_area(&disjoint[1])), (4f64, 1f64));
+
+	let inner = square(Vec2D::new(0.5, 0.5), 1f64);
+	assert_eq!(polygon_union(&a, &inner), vec![a.clone()]);
+	assert_eq!(polygon_union(&inner, &a), vec![a.clone()]);
+}
+
+#[test]
+fn clip_polygon_triangle_against_window() {
+	use clip::clip_polygon;
+	use polygon::signed_area;
+	let window = [Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 0f64), Vec2D::new(4f64, 4f64), Vec2D::new(0f64, 4f64)];
+	// poking out through the window's right side
+	let triangle = [Vec2D::new(1f64, 1f64), Vec2D::new(7f64, 2f64), Vec2D::new(1f64, 3f64)];
+	let clipped = clip_polygon(&triangle, &window);
+	assert_eq!(clipped.len(), 4);
+	let expected = [Vec2D::new(1f64, 1f64), Vec2D::new(4f64, 1.5), Vec2D::new(4f64, 2.5), Vec2D::new(1f64, 3f64)];
+	for (&p, &q) in clipped.iter().zip(expected.iter()) {
+		assert!((p - q).magnitude() < 1e-12, "{:?} vs {:?}", p, q);
+	}
+	assert!((signed_area(&clipped) - 4.5).abs() < 1e-12);
+
+	// winding of the window doesn't matter, and a fully enclosed subject is untouched
+	let mut reversed = window;
+	reversed.reverse();
+	let inner = [Vec2D::new(1f64, 1f64), Vec2D::new(2f64, 1f64), Vec2D::new(1f64, 2f64)];
+	assert_eq!(clip_polygon(&inner, &reversed), inner.to_vec());
+
+	let outside = [Vec2D::new(5f64, 5f64), Vec2D::new(6f64, 5f64), Vec2D::new(5f64, 6f64)];
+	assert!(clip_polygon(&outside, &window).is_empty());
+}
+
+#[test]
+fn clip_line_crossing_inside_and_outside() {
+	use shapes::AABB;
+	use clip::clip_line;
+	let window = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(4f64, 2f64));
+	// crossing the window diagonally, clipped at the left and right edges
+	let (start, end) = clip_line(Vec2D::new(-2f64, 0f64), Vec2D::new(6f64, 2f64), &window).unwrap();
+	assert!((start - Vec2D::new(0f64, 0.5)).magnitude() < 1e-12 && (end - Vec2D::new(4f64, 1.5)).magnitude() < 1e-12);
+	// and the other way round
+	let (start, end) = clip_line(Vec2D::new(6f64, 2f64), Vec2D::new(-2f64, 0f64), &window).unwrap();
+	assert!((start - Vec2D::new(4f64, 1.5)).magnitude() < 1e-12 && (end - Vec2D::new(0f64, 0.5)).magnitude() < 1e-12);
+
+	let inside = (Vec2D::new(1f64, 1f64), Vec2D::new(3f64, 0.5));
+	assert_eq!(clip_line(inside.0, inside.1, &window), Some(inside));
+
+	// entirely to one side, and passing by a corner without entering
+	assert_eq!(clip_line(Vec2D::new(5f64, -1f64), Vec2D::new(7f64, 3f64), &window), None);
+	assert_eq!(clip_line(Vec2D::new(3f64, 4f64), Vec2D::new(6f64, 1f64), &window), None);
+}
+
+#[test]
+fn animation_track_sampling() {
+	use anim::AnimationTrack;
+	let track = AnimationTrack::new(vec![
+		(3f64, Vec2D::new(4f64, 4f64)), (0f64, Vec2D::new(0f64, 0f64)), (1f64, Vec2D::new(2f64, 0f64))
+	]);
+	assert_eq!(track.keyframes[1].0, 1f64);
+	// between keyframes
+	assert_eq!(track.sample(0.25), Vec2D::new(0.5, 0f64));
+	assert_eq!(track.sample(2f64), Vec2D::new(3f64, 2f64));
+	// exactly on keyframes
+	assert_eq!(track.sample(0f64), Vec2D::new(0f64, 0f64));
+	assert_eq!(track.sample(1f64), Vec2D::new(2f64, 0f64));
+	assert_eq!(track.sample(3f64), Vec2D::new(4f64, 4f64));
+	// clamped outside the range
+	assert_eq!(track.sample(10f64), Vec2D::new(4f64, 4f64));
+	assert_eq!(track.sample(-10f64), Vec2D::new(0f64, 0f64));
+	assert_eq!(AnimationTrack::default().sample(1f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn spring_state_settles_faster_when_stiffer() {
+	use anim::SpringState;
+	let target = Vec2D::new(3f64, -2f64);
+	let dt = 1f64 / 120f64;
+	// the time after which the spring stays within a small distance of the target and (nearly) still
+	let settle_time = |stiffness: f64| {
+		let damping = 2f64 * stiffness.sqrt() * 0.5;
+		let mut spring = SpringState::new(Vec2D::new(0f64, 0f64));
+		let mut settled_at = None;
+		for step in 0..2400 {
+			spring.update(target, stiffness, damping, dt);
+			let settled = (spring.position - target).magnitude() < 0.01 && spring.velocity.magnitude() < 0.05;
+			match (settled, settled_at) {
+				(true, None) => settled_at = Some(step),
+				(false, _) => settled_at = None,
+				_ => {}
+			}
+		}
+		assert!((spring.position - target).magnitude() < 1e-6);
+		settled_at.expect("spring never settled") as f64 * dt
+	};
+	let (soft, stiff) = (settle_time(20f64), settle_time(200f64));
+	assert!(stiff < soft, "{} vs {}", stiff, soft);
+}
+
+#[test]
+fn closest_point_on_ellipse_matches_brute_force() {
+	use geometry::closest_point_on_ellipse;
+	let (center, radii) = (Vec2D::new(-1f64, 2f64), Vec2D::new(5f64, 2f64));
+	// outside along the major axis
+	let nearest = closest_point_on_ellipse(center + Vec2D::new(9f64, 0f64), center, radii);
+	assert!((nearest - (center + Vec2D::new(5f64, 0f64))).magnitude() < 1e-9, "{:?}", nearest);
+
+	let brute_force = |point: Vec2D<f64>| (0..200000).map(|i| {
+		let angle = i as f64 / 200000f64 * 2f64 * std::f64::consts::PI;
+		(center + Vec2D::new(radii.x * angle.cos(), radii.y * angle.sin()) - point).magnitude()
+	}).fold(f64::INFINITY, f64::min);
+	for &offset in [Vec2D::new(0.1, 0.05), Vec2D::new(-0.3, -0.01), Vec2D::new(3f64, 1f64), Vec2D::new(-7f64, 4f64), Vec2D::new(0.5, -6f64)].iter() {
+		let point = center + offset;
+		let nearest = closest_point_on_ellipse(point, center, radii);
+		let local = nearest - center;
+		assert!(((local.x / radii.x).powi(2) + (local.y / radii.y).powi(2) - 1f64).abs() < 1e-9);
+		assert!(((nearest - point).magnitude() - brute_force(point)).abs() < 1e-6, "{:?}", offset);
+	}
+	// near the centre the nearest boundary is across the minor axis
+	let nearest = closest_point_on_ellipse(center + Vec2D::new(0.1, 0.05), center, radii);
+	assert!(nearest.y > center.y + 1.9);
+}
+
+#[test]
+fn interpolate_dispatches_on_mode() {
+	use anim::{interpolate, InterpMode};
+	let (a, b) = (Vec2D::new(3f64, 0f64), Vec2D::new(-1f64, 1f64));
+	for &t in [0f64, 0.2, 0.5, 0.9, 1f64].iter() {
+		assert_eq!(interpolate(a, b, t, InterpMode::Linear), a.lerp(b, t));
+		assert_eq!(interpolate(a, b, t, InterpMode::Spherical), a.slerp(b, t));
+	}
+	// slerp turns at a constant rate and blends the length, where lerp cuts across
+	let quarter = Vec2D::new(1f64, 0f64).slerp(Vec2D::new(0f64, 3f64), 0.5);
+	assert!((quarter.angle() - std::f64::consts::PI / 4f64).abs() < 1e-12);
+	assert!((quarter.magnitude() - 2f64).abs() < 1e-12);
+	assert!((a.slerp(b, 1f64) - b).magnitude() < 1e-12);
+	// takes the shorter way round
+	assert!(Vec2D::new(1f64, 0f64).slerp(Vec2D::new(0f64, -1f64), 0.5).y < 0f64);
+	assert_eq!(Vec2D::new(0f64, 0f64).slerp(b, 0.5), Vec2D::new(0f64, 0f64).lerp(b, 0.5));
+}
+
+#[test]
+fn bearing_velocity_round_trip() {
+	use physics::Velocity;
+	for &bearing in [0f64, 45f64, 90f64, 135f64, 200f64, 315f64, 359f64].iter() {
+		let heading = Velocity{bearing_degrees: bearing, speed: 7.5};
+		let v: Vec2D<f64> = heading.into();
+		assert!((v.magnitude() - 7.5).abs() < 1e-12);
+		let back = Velocity::from(v);
+		assert!((back.bearing_degrees - bearing).abs() < 1e-9 && (back.speed - 7.5).abs() < 1e-12, "{:?}", back);
+	}
+	// north-east is up and to the right
+	let v: Vec2D<f64> = Velocity{bearing_degrees: 45f64, speed: 1f64}.into();
+	assert!(v.x > 0f64 && v.y > 0f64 && (v.x - v.y).abs() < 1e-12);
+
+	assert_eq!(Velocity::from(Vec2D::new(0f64, 0f64)), Velocity{bearing_degrees: 0f64, speed: 0f64});
+	let still: Vec2D<f64> = Velocity{bearing_degrees: 123f64, speed: 0f64}.into();
+	assert_eq!(still.magnitude(), 0f64);
+}
+
+#[test]
+fn radial_gravity_points_inward_and_falls_off() {
+	use field::radial_gravity;
+	let center = Vec2D::new(3f64, -1f64);
+	let mut previous = f64::INFINITY;
+	for i in 1..20 {
+		let point = center + Vec2D::from_polar(i as f64 * 0.5, i as f64);
+		let pull = radial_gravity(point, center, 10f64, 2f64);
+		// towards the centre
+		assert!((pull.to_unit() - (center - point).to_unit()).magnitude() < 1e-12);
+		assert!(pull.magnitude() < previous);
+		previous = pull.magnitude();
+	}
+	// inverse square: twice as far is a quarter of the pull
+	let near = radial_gravity(center + Vec2D::new(2f64, 0f64), center, 10f64, 2f64).magnitude();
+	let far = radial_gravity(center + Vec2D::new(4f64, 0f64), center, 10f64, 2f64).magnitude();
+	assert!((near / far - 4f64).abs() < 1e-12);
+	// finite close in, and nothing at the centre itself
+	assert!(radial_gravity(center + Vec2D::new(1e-9, 0f64), center, 10f64, 2f64).magnitude().is_finite());
+	assert_eq!(radial_gravity(center, center, 10f64, 2f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn vortex_field_is_tangential() {
+	use field::vortex_field;
+	let center = Vec2D::new(-2f64, 5f64);
+	for i in 0..12 {
+		let radius = Vec2D::from_polar(1f64 + i as f64, i as f64 * 0.7);
+		let swirl = vortex_field(center + radius, center, 1.5);
+		assert!(Vec2D::dot_product(swirl, radius).abs() < 1e-9);
+		// counter-clockwise
+		assert!(Vec2D::cross_product(radius, swirl) > 0f64);
+		let doubled = vortex_field(center + radius, center, 3f64);
+		assert!((doubled.magnitude() - 2f64 * swirl.magnitude()).abs() < 1e-9);
+		assert!((vortex_field(center + radius, center, -1.5) + swirl).magnitude() < 1e-12);
+	}
+}
+
+#[test]
+fn cell_hash_is_stable_and_well_mixed() {
+	use std::collections::HashSet;
+	// fixed values, so a change to the hash (which would reshuffle every seeded world) doesn't go unnoticed
+	assert_eq!(Vec2D::new(0, 0).seeded_hash(0), 0xA706_DD2F_4D19_7E6F);
+	assert_eq!(Vec2D::new(12, -34).seeded_hash(99), 0x40D3_1232_8527_64EE);
+
+	let cells: Vec<Vec2D<i32>> = (-32..32).flat_map(|x| (-32..32).map(move |y| Vec2D::new(x, y))).collect();
+	let hashes: Vec<u64> = cells.iter().map(|cell| cell.seeded_hash(7)).collect();
+	assert_eq!(hashes.iter().collect::<HashSet<_>>().len(), hashes.len());
+	// every bit is set about half the time
+	for bit in 0..64 {
+		let set = hashes.iter().filter(|&&h| h & (1 << bit) != 0).count() as f64 / hashes.len() as f64;
+		assert!((set - 0.5).abs() < 0.03, "bit {} set {}", bit, set);
+	}
+	// and neighbouring cells (or seeds) differ in about half their bits
+	let neighbour_flips: f64 = cells.iter().map(|&cell| (cell.seeded_hash(7) ^ (cell + Vec2D::new(1, 0)).seeded_hash(7)).count_ones() as f64).sum::<f64>() / cells.len() as f64;
+	let seed_flips: f64 = cells.iter().map(|&cell| (cell.seeded_hash(7) ^ cell.seeded_hash(8)).count_ones() as f64).sum::<f64>() / cells.len() as f64;
+	assert!((neighbour_flips - 32f64).abs() < 1f64 && (seed_flips - 32f64).abs() < 1f64, "{} {}", neighbour_flips, seed_flips);
+
+	// `Hash::hash` is still reachable in method-call syntax
+	use std::collections::hash_map::DefaultHasher;
+	use std::hash::{Hash, Hasher};
+	let (mut first, mut second) = (DefaultHasher::new(), DefaultHasher::new());
+	Vec2D::new(3, -7).hash(&mut first);
+	Vec2D::new(3, -7).hash(&mut second);
+	assert_eq!(first.finish(), second.finish());
+}
+
+#[test]
+fn perlin_noise_is_deterministic_bounded_and_smooth() {
+	use noise::perlin_2d;
+	let h = 1e-3;
+	let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+	for i in 0..80 {
+		for j in 0..80 {
+			// offset so samples straddle the lattice lines
+			let p = Vec2D::new(i as f64 * 0.25 - 10f64 + h / 2f64, j as f64 * 0.25 - 10f64 + h / 2f64);
+			let n = perlin_2d(p, 11);
+			assert_eq!(n, perlin_2d(p, 11));
+			assert!((-1f64..=1f64).contains(&n));
+			min = min.min(n);
+			max = max.max(n);
+			for &axis in [Vec2D::new(h, 0f64), Vec2D::new(0f64, h)].iter() {
+				let (before, after) = (perlin_2d(p - axis, 11), perlin_2d(p + axis, 11));
+				// no jumps in the value, nor (as the second difference stays O(h²)) in its slope
+				assert!((after - n).abs() < 5f64 * h && (n - before).abs() < 5f64 * h);
+				assert!((after - 2f64 * n + before).abs() < 50f64 * h * h);
+			}
+		}
+	}
+	assert!(min < -0.5 && max > 0.5, "{} {}", min, max);
+	assert_ne!(perlin_2d(Vec2D::new(0.5, 0.5), 11), perlin_2d(Vec2D::new(0.5, 0.5), 12));
+}
+
+#[test]
+fn fbm_octaves_add_detail_within_bounds() {
+	use noise::{fbm, perlin_2d};
+	let noise = |q: Vec2D<f64>| perlin_2d(q, 5);
+	// the average bend (second difference) between closely spaced samples, which grows with high-frequency detail
+	let roughness = |octaves: usize| {
+		let step = Vec2D::new(0.01, 0f64);
+		(0..2000).map(|i| {
+			let p = Vec2D::new(i as f64 * 0.01, 0.37);
+			let n = fbm(p, octaves, 2f64, 0.5, noise);
+			assert!((-1f64..=1f64).contains(&n));
+			(fbm(p + step, octaves, 2f64, 0.5, noise) - 2f64 * n + fbm(p - step, octaves, 2f64, 0.5, noise)).abs()
+		}).sum::<f64>() / 2000f64
+	};
+	let (one, three, six) = (roughness(1), roughness(3), roughness(6));
+	assert!(one < three && three < six, "{} {} {}", one, three, six);
+	assert_eq!(fbm(Vec2D::new(1f64, 1f64), 0, 2f64, 0.5, noise), 0f64);
+	// a gain above one weights the finest octave most, but the result is still normalised
+	assert!(fbm(Vec2D::new(1.1, 2.2), 8, 2f64, 1.5, noise).abs() <= 1f64);
+}
+
+#[test]
+fn domain_warp_displaces_deterministically() {
+	use noise::{domain_warp, perlin_2d};
+	let noise = |q: Vec2D<f64>| perlin_2d(q, 9);
+	let mut displaced = 0;
+	for i in 0..50 {
+		let p = Vec2D::new(i as f64 * 0.37 - 4f64, i as f64 * 0.11 + 1f64);
+		assert_eq!(domain_warp(p, noise, 0f64), p);
+		let warped = domain_warp(p, noise, 2f64);
+		assert_eq!(warped, domain_warp(p, noise, 2f64));
+		// never further than the strength allows on each axis, and twice the strength goes twice as far
+		assert!((warped - p).x.abs() <= 2f64 && (warped - p).y.abs() <= 2f64);
+		assert!(((domain_warp(p, noise, 4f64) - p) - (warped - p) * 2f64).magnitude() < 1e-12);
+		if warped != p {
+			displaced += 1;
+		}
+	}
+	assert!(displaced > 40);
+}
+
+#[test]
+fn gradient_of_distance_is_radial() {
+	use field::gradient;
+	use sdf::sdf_circle;
+	for i in 1..16 {
+		let p = Vec2D::from_polar(i as f64 * 0.6, i as f64 * 1.1);
+		let slope = gradient(|q| q.magnitude(), p, 1e-5);
+		assert!((slope - p.to_unit()).magnitude() < 1e-6, "{:?}", slope);
+		// an SDF's gradient is its surface normal
+		let normal = gradient(|q| sdf_circle(q, Vec2D::new(1f64, 1f64), 2f64), p, 1e-5);
+		assert!((normal - (p - Vec2D::new(1f64, 1f64)).to_unit()).magnitude() < 1e-6);
+	}
+}
+
+#[test]
+fn divergence_and_curl_of_source_and_vortex() {
+	use field::{divergence, curl, vortex_field};
+	let center = Vec2D::new(2f64, -1f64);
+	let source = |p: Vec2D<f64>| (p - center) * 0.5;
+	let swirl = |p: Vec2D<f64>| vortex_field(p, center, 1.5);
+	for i in 0..10 {
+		let p = center + Vec2D::from_polar(0.5 + i as f64, i as f64);
+		assert!((divergence(source, p, 1e-4) - 1f64).abs() < 1e-6);
+		assert!(curl(source, p, 1e-4).abs() < 1e-6);
+		assert!(divergence(swirl, p, 1e-4).abs() < 1e-6);
+		assert!((curl(swirl, p, 1e-4) - 3f64).abs() < 1e-6);
+	}
+	// an inward-pulling field is a sink
+	assert!(divergence(|p: Vec2D<f64>| -p, Vec2D::new(1f64, 1f64), 1e-4) < 0f64);
+}
+
+#[test]
+fn streamlines_follow_constant_and_vortex_fields() {
+	use field::{trace_streamline, vortex_field};
+	let start = Vec2D::new(1f64, -2f64);
+	let flow = Vec2D::new(0.6, 0.8);
+	let straight = trace_streamline(|_| flow, start, 0.1, 50);
+	assert_eq!(straight.len(), 51);
+	for (i, &p) in straight.iter().enumerate() {
+		assert!((p - (start + flow * (i as f64 * 0.1))).magnitude() < 1e-9);
+	}
+
+	// a vortex carries the point round a circle about its centre
+	let center = Vec2D::new(0f64, 0f64);
+	let swirl = trace_streamline(|p| vortex_field(p, center, 1f64), Vec2D::new(2f64, 0f64), 0.01, 157);
+	for &p in swirl.iter() {
+		assert!(((p - center).magnitude() - 2f64).abs() < 1e-6);
+	}
+	// a quarter turn (π/2 time units at one radian per unit) later it is near the top
+	assert!((swirl[157] - Vec2D::new(0f64, 2f64)).magnitude() < 0.01, "{:?}", swirl[157]);
+}
+
+#[test]
+fn bilinear_sample_cells_and_midpoints() {
+	use raster::bilinear_sample;
+	let (width, height) = (4, 3);
+	let grid: Vec<f64> = (0..width * height).map(|i| ((i * 7) % 5) as f64).collect();
+	for y in 0..height {
+		for x in 0..width {
+			assert_eq!(bilinear_sample(&grid, width, height, Vec2D::new(x as f64, y as f64)), grid[y * width + x]);
+		}
+	}
+	let corners = grid[width + 1] + grid[width + 2] + grid[2 * width + 1] + grid[2 * width + 2];
+	assert_eq!(bilinear_sample(&grid, width, height, Vec2D::new(1.5, 1.5)), corners / 4f64);
+	// out of bounds clamps to the nearest edge
+	assert_eq!(bilinear_sample(&grid, width, height, Vec2D::new(-3f64, -1f64)), grid[0]);
+	assert_eq!(bilinear_sample(&grid, width, height, Vec2D::new(10f64, 1f64)), grid[2 * width - 1]);
+	assert_eq!(bilinear_sample(&grid, width, height, Vec2D::new(0.5, 9f64)), (grid[2 * width] + grid[2 * width + 1]) / 2f64);
+}
+
+#[test]
+fn bicubic_sample_interpolates_smoothly() {
+	use raster::{bilinear_sample, bicubic_sample};
+	let (width, height) = (5, 4);
+	let grid: Vec<f64> = (0..width * height).map(|i| ((i * 3) % 7) as f64 - 2f64).collect();
+	for y in 0..height {
+		for x in 0..width {
+			let p = Vec2D::new(x as f64, y as f64);
+			assert!((bicubic_sample(&grid, width, height, p) - grid[y * width + x]).abs() < 1e-12);
+		}
+	}
+
+	// a ramp that levels off: bilinear sampling has a kink where it does, bicubic doesn't
+	let ramp = [0f64, 1f64, 2f64, 3f64, 3f64, 3f64];
+	let h = 1e-4;
+	let kink = |sample: &dyn Fn(f64) -> f64| {
+		let left = (sample(3f64) - sample(3f64 - h)) / h;
+		let right = (sample(3f64 + h) - sample(3f64)) / h;
+		(right - left).abs()
+	};
+	let bilinear_kink = kink(&|x| bilinear_sample(&ramp, 6, 1, Vec2D::new(x, 0f64)));
+	let bicubic_kink = kink(&|x| bicubic_sample(&ramp, 6, 1, Vec2D::new(x, 0f64)));
+	assert!((bilinear_kink - 1f64).abs() < 1e-6);
+	assert!(bicubic_kink < 1e-3, "{}", bicubic_kink);
+}
+
+#[test]
+fn splat_to_grid_peaks_and_conserves_density() {
+	use shapes::AABB;
+	use raster::splat_to_grid;
+	let bounds = AABB::new(Vec2D::new(-5f64, -5f64), Vec2D::new(5f64, 5f64));
+	let resolution = Vec2D::new(11, 11);
+	let density = splat_to_grid(&[Vec2D::new(0f64, 0f64)], &bounds, resolution, 1.5);
+	let at = |x: usize, y: usize| density[y * resolution.x + x];
+	// the peak is in the centre cell, falling away in every direction
+	let peak = at(5, 5);
+	assert!(density.iter().all(|&d| d <= peak));
+	for step in 0..5 {
+		assert!(at(5 + step, 5) > at(6 + step, 5) && at(5, 5 - step) > at(5, 4 - step));
+		assert!((at(5 + step, 5) - at(5 - step, 5)).abs() < 1e-12 && (at(5, 5 + step) - at(5 + step, 5)).abs() < 1e-12);
+	}
+	assert!((density.iter().sum::<f64>() - 1f64).abs() < 1e-12);
+
+	// several points, some near the edge, each add one
+	let points = [Vec2D::new(4.9, 4.9), Vec2D::new(-3f64, 1f64), Vec2D::new(0.2, -4.5), Vec2D::new(1f64, 1f64)];
+	let density = splat_to_grid(&points, &bounds, resolution, 0.8);
+	assert!((density.iter().sum::<f64>() - 4f64).abs() < 1e-9);
+	// and ones far outside the grid are dropped
+	assert!(splat_to_grid(&[Vec2D::new(50f64, 0f64)], &bounds, resolution, 0.8).iter().all(|&d| d == 0f64));
+}
+
+#[test]
+fn kmeans_separates_two_blobs() {
+	use cluster::kmeans;
+	let blob = |center: Vec2D<f64>, stream: i32| (0..30).map(move |i| {
+		center + Vec2D::new(::noise::lattice_value(i, stream, 3) - 0.5, ::noise::lattice_value(i, stream + 1, 3) - 0.5) * 2f64
+	});
+	let points: Vec<Vec2D<f64>> = blob(Vec2D::new(-10f64, 4f64), 0).chain(blob(Vec2D::new(12f64, -3f64), 2)).collect();
+	for seed in 0..5 {
+		let clusters = kmeans(&points, 2, 50, seed);
+		assert_eq!(clusters.len(), points.len());
+		assert!(clusters[..30].iter().all(|&c| c == clusters[0]));
+		assert!(clusters[30..].iter().all(|&c| c == clusters[30]));
+		assert_ne!(clusters[0], clusters[30]);
+		assert_eq!(clusters, kmeans(&points, 2, 50, seed));
+	}
+	assert!(kmeans(&points, 1, 10, 0).iter().all(|&c| c == 0));
+	assert!(kmeans(&[], 3, 10, 0).is_empty());
+}
+
+#[test]
+fn dbscan_separates_blobs_from_noise() {
+	use cluster::dbscan;
+	// a dense ring (which k-means couldn't pick out) and a dense blob, with sparse noise scattered around them
+	let mut points: Vec<Vec2D<f64>> = (0..100).map(|i| Vec2D::from_polar(5f64, i as f64 / 100f64 * 2f64 * std::f64::consts::PI)).collect();
+	points.extend((0..40).map(|i| Vec2D::new(20f64, 0f64) + Vec2D::new(::noise::lattice_value(i, 0, 5), ::noise::lattice_value(i, 1, 5)) * 2f64));
+	let noise = [Vec2D::new(-15f64, 12f64), Vec2D::new(10f64, 10f64), Vec2D::new(0f64, 0f64), Vec2D::new(30f64, -9f64), Vec2D::new(-8f64, -12f64)];
+	points.extend(noise.iter().cloned());
+
+	let labels = dbscan(&points, 1f64, 4);
+	assert!(labels[..100].iter().all(|&l| l.is_some() && l == labels[0]));
+	assert!(labels[100..140].iter().all(|&l| l.is_some() && l == labels[100]));
+	assert_ne!(labels[0], labels[100]);
+	assert!(labels[140..].iter().all(|l| l.is_none()));
+	// ids are numbered from zero in the order clusters are found
+	assert_eq!((labels[0], labels[100]), (Some(0), Some(1)));
+}
+
+#[test]
+fn concave_hull_follows_c_shape() {
+	use geometry::concave_hull;
+	use polygon::{convex_hull, point_in_polygon, signed_area};
+	// a C: a 7x7 grid of points with the middle of its right side cut away
+	let points: Vec<Vec2D<f64>> = (0..7).flat_map(|x| (0..7).map(move |y| Vec2D::new(x as f64, y as f64)))
+		.filter(|p| !(p.x >= 2f64 && p.y >= 2f64 && p.y <= 4f64)).collect();
+	let inside_gap = Vec2D::new(4.5, 3f64);
+	assert!(point_in_polygon(inside_gap, &convex_hull(&points)));
+
+	let hull = concave_hull(&points, 1f64);
+	assert!(!point_in_polygon(inside_gap, &hull));
+	assert!(signed_area(&hull) > 0f64);
+	// the 6x6 square less the notch between rows 1 and 5, whose inner end is cut diagonally from (1, 2) and (1, 4) into (2, 1) and (2, 5)
+	assert!((signed_area(&hull) - 17f64).abs() < 1e-9, "{}", signed_area(&hull));
+	for &p in points.iter() {
+		assert!(hull.contains(&p) || point_in_polygon(p, &hull));
+	}
+	// a large alpha gives back the convex hull's area
+	assert!((signed_area(&concave_hull(&points, 100f64)) - 36f64).abs() < 1e-9);
+}
+
+#[test]
+fn medial_axis_of_rectangle_is_its_centre_line() {
+	use polygon::medial_axis_approx;
+	let rectangle = [Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64), Vec2D::new(10f64, 2f64), Vec2D::new(0f64, 2f64)];
+	let axis = medial_axis_approx(&rectangle, 120);
+	assert!(!axis.is_empty());
+	// every segment lies inside the rectangle, and those clear of the corner branches lie on the long centre-line
+	let mut covered = Vec::new();
+	for segment in axis.iter() {
+		for &p in [segment.start, segment.end].iter() {
+			assert!(p.x > 0f64 && p.x < 10f64 && p.y > 0f64 && p.y < 2f64);
+		}
+		if segment.start.x.min(segment.end.x) > 1.5 && segment.start.x.max(segment.end.x) < 8.5 {
+			assert!((segment.start.y - 1f64).abs() < 0.05 && (segment.end.y - 1f64).abs() < 0.05, "{:?}", segment);
+			covered.push((segment.start.x.min(segment.end.x), segment.start.x.max(segment.end.x)));
+		}
+	}
+	// and together they run the length of it, without gaps
+	covered.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+	let mut reach = covered[0].1;
+	assert!(covered[0].0 < 2f64);
+	for &(start, end) in covered.iter().skip(1) {
+		assert!(start <= reach + 0.2, "gap from {} to {}", reach, start);
+		reach = reach.max(end);
+	}
+	assert!(reach > 8f64);
+}
+
+#[test]
+fn path_follower_visits_waypoints_and_stops() {
+	use steering::PathFollower;
+	let waypoints = vec![Vec2D::new(5f64, 0f64), Vec2D::new(5f64, 5f64), Vec2D::new(0f64, 5f64), Vec2D::new(0f64, 10f64)];
+	let mut follower = PathFollower::new(waypoints.clone());
+	let mut position = Vec2D::new(0f64, 0f64);
+	let dt = 1f64 / 30f64;
+	let mut passed = vec![false; waypoints.len()];
+	let mut previous = 0;
+	for _ in 0..900 {
+		let velocity = follower.steering(position, 0.5, 3f64);
+		assert!(velocity.magnitude() <= 3f64 + 1e-9);
+		assert!(follower.current >= previous);
+		previous = follower.current;
+		position += velocity * dt;
+		for (i, &waypoint) in waypoints.iter().enumerate() {
+			passed[i] |= (waypoint - position).magnitude() < 0.6;
+		}
+	}
+	assert!(passed.iter().all(|&p| p));
+	assert_eq!(follower.current, waypoints.len() - 1);
+	// stopped at the end
+	assert!((position - Vec2D::new(0f64, 10f64)).magnitude() < 1e-3);
+	assert!(follower.steering(position, 0.5, 3f64).magnitude() < 1e-2);
+
+	assert_eq!(PathFollower::new(Vec::new()).steering(position, 0.5, 3f64), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn context_steering_deflects_around_obstacle_ahead() {
+	use shapes::Circle;
+	use steering::context_steering;
+	let position = Vec2D::new(0f64, 0f64);
+	let ahead = Vec2D::new(1f64, 0f64);
+	let obstacle = Circle::new(Vec2D::new(3f64, 0f64), 1f64);
+	let chosen = context_steering(position, ahead, &[obstacle], 32);
+	assert!((chosen.magnitude() - 1f64).abs() < 1e-12);
+	// still heading broadly forwards, but off to a side far enough to clear the obstacle
+	assert!(chosen.x > 0f64 && chosen.y.abs() > 0.1);
+	assert!(::raycast::ray_circle_intersection(position, chosen, &obstacle).is_none());
+
+	// an obstacle far away barely matters, and nor does one off to the side
+	let distant = context_steering(position, ahead, &[Circle::new(Vec2D::new(500f64, 0f64), 1f64)], 32);
+	assert!((distant - ahead).magnitude() < 1e-12);
+	let beside = context_steering(position, ahead, &[Circle::new(Vec2D::new(0f64, 3f64), 1f64)], 32);
+	assert!((beside - ahead).magnitude() < 1e-12);
+	assert_eq!(context_steering(position, Vec2D::new(0f64, 0f64), &[obstacle], 32), Vec2D::new(0f64, 0f64));
+}
+
+#[test]
+fn reciprocal_velocity_head_on_agents_pass() {
+	use steering::reciprocal_velocity;
+	let radius = 0.5;
+	let goals = [Vec2D::new(10f64, 0f64), Vec2D::new(0f64, 0f64)];
+	let mut positions = [Vec2D::new(0f64, 0f64), Vec2D::new(10f64, 0f64)];
+	let mut velocities = [Vec2D::new(1f64, 0f64), Vec2D::new(-1f64, 0f64)];
+	let dt = 0.05;
+	let mut closest = f64::INFINITY;
+	for _ in 0..400 {
+		let mut next = velocities;
+		for i in 0..2 {
+			let other = 1 - i;
+			let to_goal = goals[i] - positions[i];
+			let preferred = if to_goal.magnitude() < 1e-3 { Vec2D::new(0f64, 0f64) } else { to_goal.to_unit() * to_goal.magnitude().min(1f64) };
+			next[i] = reciprocal_velocity(positions[i], preferred, &[(positions[other], velocities[other], radius)], radius, 3f64);
+		}
+		velocities = next;
+		for i in 0..2 {
+			positions[i] += velocities[i] * dt;
+		}
+		closest = closest.min((positions[0] - positions[1]).magnitude());
+	}
+	// they swerved rather than colliding, and got past each other to their goals
+	assert!(closest >= 2f64 * radius, "{}", closest);
+	assert!((positions[0] - goals[0]).magnitude() < 0.1 && (positions[1] - goals[1]).magnitude() < 0.1, "{:?}", positions);
+}
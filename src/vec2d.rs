@@ -1,10 +1,13 @@
 use std;
 use std::ops::*;
 use std::fmt::Debug;
+use scalar::Scalar;
+use shapes::AABB;
+use sane_mod;
 /// a fairly straight-forward 2D vector type (in the mathematical sense), generally supporting:
 /// PartialEq, partialordering, addition, subtraction, scalar multiplication, scalar division, and dot-product operations
 /// Note: a default implementation is provided for these implementations but these are provide when the support of the relevant traits/operations is present in the scalar types used
-#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Default)]
 pub struct Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
 	pub x: T,
 	pub y: T
@@ -14,7 +17,7 @@ pub struct Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
 impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 	/// Creates a new `Vec2D` by assigning the parameters to the x and y members respectively.
 	pub fn new(x: T, y: T) -> Vec2D<T> {
-		Vec2D{x: x, y: y}
+		Vec2D{x, y}
 	}
 
 	/// Dot product, AKA scalar vector product, this takes two vectors and returns their contained scalar type be summing the products of each dimension.
@@ -30,6 +33,29 @@ impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 		a.x*b.x + a.y*b.y
 	}
 
+	/// 2D cross product (AKA perp-dot product), the z component of the 3D cross product of the two vectors.
+	/// Positive when `b` is counter-clockwise from `a`, negative when clockwise, and zero when they are parallel.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let (a, b) = (Vec2D::new(1, 0), Vec2D::new(0, 1));
+	/// 	assert_eq!(Vec2D::cross_product(a, b), 1);
+	/// 	assert_eq!(Vec2D::cross_product(b, a), -1);
+	/// ```
+	pub fn cross_product(a: Vec2D<T>, b: Vec2D<T>) -> T where T: Sub<Output=T>+Mul<Output=T> {
+		a.x*b.y - a.y*b.x
+	}
+
+	/// Gets the perpendicular vector, rotated a quarter turn counter-clockwise (i.e. `(-y, x)`).
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(2, 1).perp(), Vec2D::new(-1, 2));
+	/// ```
+	pub fn perp(self) -> Vec2D<T> where T: Neg<Output=T> {
+		Vec2D::new(-self.y, self.x)
+	}
+
 	/// determines the magnitude of the vector's polar form in terms it's scalar type.
 	/// # Examples
 	/// ```
@@ -37,8 +63,19 @@ impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 	/// 	let b = Vec2D::new(3, 7);
 	/// 	assert_eq!(b.magnitude(), 7.615773105863909);
 	/// ```
-	pub fn magnitude (&self) -> f64 where T: Add<Output=T>+Mul<Output=T>, f64: std::convert::From<T> {
-		f64::from(self.x*self.x + self.y*self.y).sqrt()
+	pub fn magnitude (&self) -> f64 where T: Scalar {
+		(self.x*self.x + self.y*self.y).to_f64().sqrt()
+	}
+
+	/// the squared distance between this point and `other`, in the scalar type (cheaper than the distance, for comparisons)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1, 2).distance_squared(Vec2D::new(4, 6)), 25);
+	/// ```
+	pub fn distance_squared(self, other: Vec2D<T>) -> T where T: Add<Output=T>+Sub<Output=T>+Mul<Output=T> {
+		let offset = self - other;
+		Vec2D::dot_product(offset, offset)
 	}
 
 	/// gets the angle of the vector's polar form
@@ -48,8 +85,32 @@ impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 	/// 	use phys2d::vec2d::Vec2D;
 	/// 	assert_eq!(Vec2D::new(0, 1).angle(), std::f64::consts::PI/2f64);
 	/// ```
-	pub fn angle (&self) -> f64 where T: Add<Output=T>+Mul<Output=T>, f64: std::convert::From<T> {
-		return f64::from(self.y).atan2(f64::from(self.x));
+	pub fn angle (&self) -> f64 where T: Scalar {
+		self.y.to_f64().atan2(self.x.to_f64())
+	}
+
+	/// gets the angle of the vector's polar form in turns (fractions of a full rotation), in `(-0.5, 0.5]`
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(0, 1).angle_turns(), 0.25);
+	/// 	assert_eq!(Vec2D::new(-1, 0).angle_turns(), 0.5);
+	/// ```
+	pub fn angle_turns(&self) -> f64 where T: Scalar {
+		self.angle() / (2f64 * std::f64::consts::PI)
+	}
+
+	/// gets the vector's compass bearing in degrees, in `[0, 360)`: measured clockwise from north (the positive y axis),
+	/// so east (the positive x axis) is 90
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1, 0).bearing_degrees(), 90f64);
+	/// 	assert_eq!(Vec2D::new(0, -1).bearing_degrees(), 180f64);
+	/// 	assert_eq!(Vec2D::new(-1, 0).bearing_degrees(), 270f64);
+	/// ```
+	pub fn bearing_degrees(&self) -> f64 where T: Scalar {
+		sane_mod(self.x.to_f64().atan2(self.y.to_f64()).to_degrees(), 360f64)
 	}
 
 	/// Creates a unit equivilant of the vector (same direction, magnitude 1)
@@ -63,9 +124,40 @@ impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 	/// 	assert!(b.magnitude()-1f64 < std::f64::EPSILON);
 	/// 	assert!(a.magnitude() != 1f64);
 	/// ```
-	pub fn to_unit(self) -> Vec2D<f64> where T: Add<Output=T>+Mul<Output=T>, f64: std::convert::From<T> {
+	pub fn to_unit(self) -> Vec2D<f64> where T: Scalar {
 		let mag = self.magnitude();
-		Vec2D::new(f64::from(self.x)/mag, f64::from(self.y)/mag)
+		Vec2D::new(self.x.to_f64()/mag, self.y.to_f64()/mag)
+	}
+
+	/// Creates a unit equivilant of the vector like `to_unit`, but scales by the largest component first
+	/// so that very small or very large vectors don't underflow or overflow when squared. The zero vector stays zero.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let tiny = Vec2D::new(3e-200, 4e-200);
+	/// 	assert!(!tiny.to_unit().x.is_finite());
+	/// 	assert_eq!(tiny.normalize_stable(), Vec2D::new(0.6, 0.8));
+	/// ```
+	pub fn normalize_stable(self) -> Vec2D<f64> where T: Scalar {
+		let (x, y) = (self.x.to_f64(), self.y.to_f64());
+		let scale = x.abs().max(y.abs());
+		if scale == 0f64 {
+			return Vec2D::default();
+		}
+		Vec2D::new(x/scale, y/scale).to_unit()
+	}
+
+	/// Rotates the vector counter-clockwise about the origin by `radians`.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let r = Vec2D::new(2, 0).rotate(std::f64::consts::PI);
+	/// 	assert!((r - Vec2D::new(-2f64, 0f64)).magnitude() < 1e-12);
+	/// ```
+	pub fn rotate(self, radians: f64) -> Vec2D<f64> where T: Scalar {
+		let (sin, cos) = radians.sin_cos();
+		let (x, y) = (self.x.to_f64(), self.y.to_f64());
+		Vec2D::new(x*cos - y*sin, x*sin + y*cos)
 	}
 
 	/// Projects a onto b
@@ -75,27 +167,231 @@ impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default,  {
 	/// 	let a = Vec2D::new(5,5);
 	///		assert_eq!(a.project_onto(Vec2D::new(0,1)), Vec2D::new(0f64,5f64));
 	/// ```
-	pub fn project_onto(self, b: Vec2D<T>) -> Vec2D<f64> where T: Add<Output=T>+Mul<Output=T>, Vec2D<f64>: std::convert::From<Vec2D<T>>, f64: std::convert::From<T>
+	pub fn project_onto(self, b: Vec2D<T>) -> Vec2D<f64> where T: Scalar
 	{
 		let unit_b = b.to_unit();
-		unit_b*(Vec2D::dot_product(<Vec2D<f64>>::from(self), unit_b))
+		unit_b*(Vec2D::dot_product(Vec2D::new(self.x.to_f64(), self.y.to_f64()), unit_b))
 	}
 
 	///gets an all-positive version of the vector
-	pub fn abs(self) -> Vec2D<T>
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(-3, 4).abs(), Vec2D::new(3, 4));
+	/// 	assert_eq!(Vec2D::new(2.5, -0.5).abs(), Vec2D::new(2.5, 0.5));
+	/// ```
+	pub fn abs(self) -> Vec2D<T> where T: Neg<Output=T>
 	{
-		unimplemented!();
+		self.map(|c| if c < T::default() { -c } else { c })
+	}
+
+	/// Applies `f` to each component, producing a vector of the results.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1u32, 2u32).map(|c| c as f64 * 1.5), Vec2D::new(1.5f64, 3f64));
+	/// ```
+	pub fn map<U, F>(self, f: F) -> Vec2D<U> where U: Copy+Debug+PartialEq+PartialOrd+Default, F: Fn(T) -> U {
+		Vec2D::new(f(self.x), f(self.y))
+	}
+
+	/// Clamps both components to the same `[min, max]` range (assuming `min <= max`).
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(-5, 3).clamp_scalar(0, 10), Vec2D::new(0, 3));
+	/// 	assert_eq!(Vec2D::new(0.5, 12.5).clamp_scalar(0f64, 1f64), Vec2D::new(0.5, 1f64));
+	/// ```
+	pub fn clamp_scalar(self, min: T, max: T) -> Vec2D<T> {
+		self.map(|c| if c < min { min } else if c > max { max } else { c })
+	}
+
+	/// Converts each component into another scalar type that it losslessly converts into.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let v: Vec2D<f64> = Vec2D::new(3i16, -4i16).cast();
+	/// 	assert_eq!(v.magnitude(), 5f64);
+	/// ```
+	pub fn cast<U>(self) -> Vec2D<U> where U: Copy+Debug+PartialEq+PartialOrd+Default+From<T> {
+		self.map(U::from)
+	}
+
+	/// Determines which of `num_bins` equal angular sectors the vector's direction falls into.
+	/// Sectors cover `[0, 2π)` counter-clockwise starting from the positive x axis, so bin 0 spans `[0, 2π/num_bins)`.
+	/// The zero vector has no direction and is placed in bin 0.
+	/// Panics if `num_bins` is zero.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1, 1).angle_bin(4), 0);
+	/// 	assert_eq!(Vec2D::new(-1, 1).angle_bin(4), 1);
+	/// 	assert_eq!(Vec2D::new(1, -1).angle_bin(4), 3);
+	/// ```
+	pub fn angle_bin(&self, num_bins: usize) -> usize where T: Scalar {
+		assert!(num_bins > 0, "angle_bin requires at least one bin");
+		let mut turns = self.angle() / (2f64 * std::f64::consts::PI);
+		if turns < 0f64 {
+			turns += 1f64;
+		}
+		((turns * num_bins as f64).floor() as usize) % num_bins
+	}
+
+	/// Formats the vector as `(x, y)` with each component rounded to `decimals` decimal places, for logging.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(1.23456, 6.54321).debug_rounded(2), "(1.23, 6.54)");
+	/// ```
+	pub fn debug_rounded(&self, decimals: usize) -> String where T: Scalar {
+		format!("({:.*}, {:.*})", decimals, self.x.to_f64(), decimals, self.y.to_f64())
 	}
 }
 
 impl std::convert::From<Vec2D<i32>> for Vec2D<f64> {
 	fn from(src: Vec2D<i32>) -> Vec2D<f64> {
-		Vec2D::new((src.x as f64), (src.y as f64))
+		src.cast()
 	}
 }
 
-impl<T> Add<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + Add<Output=T>, {
-	type Output = Vec2D<T>;
+impl Vec2D<f64> {
+	/// Creates a vector from its polar form.
+	/// `Vec2D::from_polar(v.magnitude(), v.angle())` reproduces `v` to within a tolerance relative to its length,
+	/// typically `f64::EPSILON * v.magnitude()` per component (and in practice within twice that).
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let v = Vec2D::from_polar(2f64, std::f64::consts::PI/2f64);
+	/// 	assert!((v - Vec2D::new(0f64, 2f64)).magnitude() < 1e-12);
+	/// ```
+	pub fn from_polar(magnitude: f64, angle: f64) -> Vec2D<f64> {
+		let (sin, cos) = angle.sin_cos();
+		Vec2D::new(magnitude*cos, magnitude*sin)
+	}
+
+	/// Creates the unit vector pointing `turns` full rotations counter-clockwise from the positive x axis (one turn being 2π radians).
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert!((Vec2D::from_turns(0.5) - Vec2D::new(-1f64, 0f64)).magnitude() < 1e-12);
+	/// ```
+	pub fn from_turns(turns: f64) -> Vec2D<f64> {
+		Vec2D::from_polar(1f64, turns * 2f64 * std::f64::consts::PI)
+	}
+
+	/// Linearly interpolates between this vector (at `t = 0`) and `other` (at `t = 1`).
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(0f64, 2f64).lerp(Vec2D::new(4f64, 6f64), 0.25), Vec2D::new(1f64, 3f64));
+	/// ```
+	pub fn lerp(self, other: Vec2D<f64>, t: f64) -> Vec2D<f64> {
+		self + (other - self)*t
+	}
+
+	/// Spherically interpolates between this vector (at `t = 0`) and `other` (at `t = 1`), turning the direction through the
+	/// smaller angle between them at a constant rate while interpolating the magnitude linearly. Suits directions, where `lerp` would
+	/// cut the corner (and shorten the vector). Falls back to `lerp` if either vector is zero and so has no direction.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let halfway = Vec2D::new(1f64, 0f64).slerp(Vec2D::new(0f64, 1f64), 0.5);
+	/// 	assert!((halfway - Vec2D::from_polar(1f64, std::f64::consts::PI/4f64)).magnitude() < 1e-12);
+	/// ```
+	pub fn slerp(self, other: Vec2D<f64>, t: f64) -> Vec2D<f64> {
+		let (from, to) = (self.magnitude(), other.magnitude());
+		if from == 0f64 || to == 0f64 {
+			return self.lerp(other, t);
+		}
+		let angle = Vec2D::cross_product(self, other).atan2(Vec2D::dot_product(self, other));
+		self.rotate(angle*t) * ((from + (to - from)*t) / from)
+	}
+
+	/// Linearly remaps each component from the range `[from_min, from_max]` to `[to_min, to_max]` (on that axis),
+	/// extrapolating for values outside the input range. An axis whose input range has zero width can't be divided by,
+	/// so every value on it maps to `to_min` there.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	let ndc = Vec2D::new(0.5, -1f64);
+	/// 	let pixel = ndc.remap(Vec2D::new(-1f64, -1f64), Vec2D::new(1f64, 1f64), Vec2D::new(0f64, 600f64), Vec2D::new(800f64, 0f64));
+	/// 	assert_eq!(pixel, Vec2D::new(600f64, 600f64));
+	/// ```
+	pub fn remap(self, from_min: Vec2D<f64>, from_max: Vec2D<f64>, to_min: Vec2D<f64>, to_max: Vec2D<f64>) -> Vec2D<f64> {
+		let remap_axis = |value: f64, from_min: f64, from_max: f64, to_min: f64, to_max: f64| {
+			let width = from_max - from_min;
+			if width == 0f64 { to_min } else { to_min + (value - from_min) / width * (to_max - to_min) }
+		};
+		Vec2D::new(remap_axis(self.x, from_min.x, from_max.x, to_min.x, to_max.x), remap_axis(self.y, from_min.y, from_max.y, to_min.y, to_max.y))
+	}
+
+	/// Wraps a position that has left `bounds` around to the opposite side, as in a world whose edges join up (e.g. Asteroids).
+	/// Positions inside the box are unchanged; the max edge is treated as the same place as the min edge.
+	/// On an axis where `bounds` has zero size there is only one place to be, so that component becomes `bounds.min`'s.
+	/// #Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	use phys2d::shapes::AABB;
+	/// 	let bounds = AABB::new(Vec2D::new(0f64, 0f64), Vec2D::new(100f64, 50f64));
+	/// 	assert_eq!(Vec2D::new(103f64, -5f64).wrap_within(&bounds), Vec2D::new(3f64, 45f64));
+	/// ```
+	pub fn wrap_within(self, bounds: &AABB<f64>) -> Vec2D<f64> {
+		let size = bounds.max - bounds.min;
+		let local = self - bounds.min;
+		let wrap_axis = |value: f64, size: f64| if size == 0f64 { 0f64 } else { sane_mod(value, size) };
+		bounds.min + Vec2D::new(wrap_axis(local.x, size.x), wrap_axis(local.y, size.y))
+	}
+
+	/// Rotates the vector counter-clockwise about the origin by `radians`, in place.
+	pub fn rotate_assign(&mut self, radians: f64) {
+		*self = self.rotate(radians);
+	}
+}
+
+impl Vec2D<f32> {
+	/// determines the magnitude of the vector, staying in single precision.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(3f32, 4f32).magnitude_f32(), 5f32);
+	/// ```
+	pub fn magnitude_f32(&self) -> f32 {
+		(self.x*self.x + self.y*self.y).sqrt()
+	}
+
+	/// determines the angle of the vector's polar form in radians, staying in single precision.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(Vec2D::new(0f32, 2f32).angle_f32(), std::f32::consts::FRAC_PI_2);
+	/// ```
+	pub fn angle_f32(&self) -> f32 {
+		self.y.atan2(self.x)
+	}
+}
+
+/// the component-wise building blocks `impl_vector_ops!` writes the operators in terms of
+impl<T> Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn map_components(self, f: impl Fn(T) -> T) -> Vec2D<T> {
+		Vec2D::new(f(self.x), f(self.y))
+	}
+
+	fn zip_components(self, rhs: Vec2D<T>, f: impl Fn(T, T) -> T) -> Vec2D<T> {
+		Vec2D::new(f(self.x, rhs.x), f(self.y, rhs.y))
+	}
+
+	fn each_component_mut(&mut self, f: impl Fn(&mut T)) {
+		f(&mut self.x);
+		f(&mut self.y);
+	}
+
+	fn zip_components_mut(&mut self, rhs: Vec2D<T>, f: impl Fn(&mut T, T)) {
+		f(&mut self.x, rhs.x);
+		f(&mut self.y, rhs.y);
+	}
+}
+
+impl_vector_ops!(Vec2D<T>, [T],
 	/// Adds two `Vec2D` together (by summing their members)
 	/// # Examples
 	/// ```
@@ -104,12 +400,8 @@ impl<T> Add<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Defa
 	/// 	let (a, b) = (Vec2D::new(x,y), Vec2D::new(u,v));
 	/// 	assert_eq!(a+b, Vec2D::new(6, 6));
 	/// ```
-	fn add(self, rhs: Vec2D<T>) -> Vec2D<T> {
-		Vec2D::new(self.x + rhs.x, self.y + rhs.y)
-	}
-}
+	add,
 
-impl<T> AddAssign<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + AddAssign {
 	/// Adds two `Vec2D` togther and stores the result into the first
 	/// # Examples
 	/// ```
@@ -119,13 +411,8 @@ impl<T> AddAssign<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOr
 	/// 	a += b;
 	/// 	assert_eq!(a, Vec2D::new(5,5));
 	/// ```
-	fn add_assign(&mut self, rhs: Vec2D<T>) {
-		self.x += rhs.x;
-		self.y += rhs.y;
-	}
-}
+	add_assign,
 
-impl<T> Sub<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + Sub<Output=T> {
 	/// Subtracts one `Vec2D` from another (by subtracting their members)
 	/// # Examples
 	/// ```
@@ -134,13 +421,8 @@ impl<T> Sub<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Defa
 	/// 	let (a, b) = (Vec2D::new(x,y), Vec2D::new(u,v));
 	/// 	assert_eq!(a-b, Vec2D::new(1, 1));
 	/// ```
-	type Output = Vec2D<T>;
-	fn sub(self, rhs: Vec2D<T>) -> Vec2D<T> {
-		Vec2D::new(self.x - rhs.x, self.y - rhs.y)
-	}
-}
+	sub,
 
-impl<T> SubAssign<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + SubAssign {
 	/// Subtracts one `Vec2D` from another and stores the result in the first
 	/// # Examples
 	/// ```
@@ -150,13 +432,7 @@ impl<T> SubAssign<Vec2D<T>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOr
 	///		a -= b;
 	/// 	assert_eq!(a, Vec2D::new(1, 1));
 	/// ```
-	fn sub_assign(&mut self, rhs: Vec2D<T>) {
-		self.x -= rhs.x;
-		self.y -= rhs.y;
-	}
-}
-impl<T> Mul<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + Mul<Output=T> {
-	type Output = Vec2D<T>;
+	sub_assign,
 
 	/// Multiplies a `Vec2D` by it's scalar `T` (by multiplying it's members by the scalar)
 	/// # Examples
@@ -166,12 +442,7 @@ impl<T> Mul<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + M
 	/// 	let a = Vec2D::new(x,y);
 	/// 	assert_eq!(a*s, Vec2D::new(6, 6));
 	/// ```
-	fn mul(self, rhs: T) -> Vec2D<T> {
-		Vec2D::new(self.x * rhs, self.y * rhs)
-	}
-} 
-
-impl<T> MulAssign<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + MulAssign {
+	mul,
 
 	/// Multiplies a `Vec2D` by it's scalar `T` and stores the result into the `Vec2D`
 	/// # Examples
@@ -182,14 +453,7 @@ impl<T> MulAssign<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Defau
 	///     a *= s;
 	/// 	assert_eq!(a, Vec2D::new(6, 6));
 	/// ```
-	fn mul_assign(&mut self, rhs: T) {
-		self.x *= rhs;
-		self.y *= rhs;
-	}
-}
-
-impl<T> Div<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + Div<Output=T> {
-	type Output = Vec2D<T>;
+	mul_assign,
 
 	/// Divides a `Vec2D` by it's scalar `T` (by dividing it's members by the scalar)
 	/// Note: truncates by default if the underlying types truncate
@@ -203,13 +467,7 @@ impl<T> Div<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + D
 	///		let b = Vec2D::new(x as f64, y as f64);
 	/// 	assert_eq!(b/(s as f64), Vec2D::new(3.5, 3.5));
 	/// ```
-	fn div(self, rhs: T) -> Vec2D<T> {
-		Vec2D::new(self.x / rhs, self.y / rhs)
-	}
-}
-
-
-impl<T> DivAssign<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + DivAssign {
+	div,
 
 	/// Divides a `Vec2D` by it's scalar `T` and stores the result into the `Vec2D`
 	/// Note: truncates by default if the underlying types truncate
@@ -225,18 +483,13 @@ impl<T> DivAssign<T> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Defau
 	///		b /= (s as f64);
 	/// 	assert_eq!(b, Vec2D::new(3.5, 3.5));
 	/// ```
-	fn div_assign(&mut self, rhs: T) {
-		self.x /= rhs;
-		self.y /= rhs;
-	}
-}
-
-impl<T> Neg for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default + Neg<Output=T>
-{
-	type Output = Self;
-	///TODO: CREATE UNIT TEST FOR THIS
-	fn neg(self) -> Vec2D<T> {
-		Vec2D::new(-self.x, -self.y)
-	}
-}
+	div_assign,
 
+	/// Negates a `Vec2D` (by negating its members)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vec2d::Vec2D;
+	/// 	assert_eq!(-Vec2D::new(3, -2), Vec2D::new(-3, 2));
+	/// ```
+	neg
+);
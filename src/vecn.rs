@@ -0,0 +1,214 @@
+use std::ops::*;
+use std::fmt::Debug;
+use scalar::Scalar;
+use vec2d::Vec2D;
+
+/// an N-dimensional vector type (in the mathematical sense) backed by an array, supporting the same
+/// arithmetic, scalar multiplication/division and dot-product operations as `Vec2D`
+/// Note: `Vec2D` remains its own type (so its `x`/`y` members stay directly accessible); conversions between `Vec2D<T>` and `VecN<T, 2>` are provided,
+/// and both types get their operators from the same `impl_vector_ops!` implementation
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct VecN<T, const N: usize> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	pub components: [T; N]
+}
+
+impl<T, const N: usize> VecN<T, N> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// Creates a new `VecN` from its components.
+	pub fn new(components: [T; N]) -> VecN<T, N> {
+		VecN{components}
+	}
+
+	/// Dot product: the sum of the products of each dimension.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	let (a, b) = (VecN::new([1, 2, 3]), VecN::new([4, 5, 6]));
+	/// 	assert_eq!(VecN::dot_product(a, b), 32);
+	/// ```
+	pub fn dot_product(a: VecN<T, N>, b: VecN<T, N>) -> T where T: Add<Output=T>+Mul<Output=T> {
+		let mut sum = T::default();
+		for i in 0..N {
+			sum = sum + a.components[i] * b.components[i];
+		}
+		sum
+	}
+
+	/// determines the magnitude (euclidean length) of the vector.
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(VecN::new([2, 3, 6]).magnitude(), 7f64);
+	/// ```
+	pub fn magnitude(&self) -> f64 where T: Scalar {
+		VecN::dot_product(*self, *self).to_f64().sqrt()
+	}
+}
+
+impl<T, const N: usize> Default for VecN<T, N> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn default() -> VecN<T, N> {
+		VecN::new([T::default(); N])
+	}
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	type Output = T;
+	fn index(&self, index: usize) -> &T {
+		&self.components[index]
+	}
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		&mut self.components[index]
+	}
+}
+
+impl<T> VecN<T, 2> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// the first component
+	pub fn x(&self) -> T {
+		self.components[0]
+	}
+
+	/// the second component
+	pub fn y(&self) -> T {
+		self.components[1]
+	}
+}
+
+impl<T> VecN<T, 3> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	/// the first component
+	pub fn x(&self) -> T {
+		self.components[0]
+	}
+
+	/// the second component
+	pub fn y(&self) -> T {
+		self.components[1]
+	}
+
+	/// the third component
+	pub fn z(&self) -> T {
+		self.components[2]
+	}
+}
+
+impl<T> From<Vec2D<T>> for VecN<T, 2> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn from(src: Vec2D<T>) -> VecN<T, 2> {
+		VecN::new([src.x, src.y])
+	}
+}
+
+impl<T> From<VecN<T, 2>> for Vec2D<T> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn from(src: VecN<T, 2>) -> Vec2D<T> {
+		Vec2D::new(src.components[0], src.components[1])
+	}
+}
+
+/// the component-wise building blocks `impl_vector_ops!` writes the operators in terms of
+impl<T, const N: usize> VecN<T, N> where T: Copy+Debug+PartialEq+PartialOrd+Default {
+	fn map_components(mut self, f: impl Fn(T) -> T) -> VecN<T, N> {
+		self.each_component_mut(|c| *c = f(*c));
+		self
+	}
+
+	fn zip_components(mut self, rhs: VecN<T, N>, f: impl Fn(T, T) -> T) -> VecN<T, N> {
+		self.zip_components_mut(rhs, |c, other| *c = f(*c, other));
+		self
+	}
+
+	fn each_component_mut(&mut self, f: impl Fn(&mut T)) {
+		for component in self.components.iter_mut() {
+			f(component);
+		}
+	}
+
+	fn zip_components_mut(&mut self, rhs: VecN<T, N>, f: impl Fn(&mut T, T)) {
+		for (component, &other) in self.components.iter_mut().zip(rhs.components.iter()) {
+			f(component, other);
+		}
+	}
+}
+
+impl_vector_ops!(VecN<T, N>, [T, const N: usize],
+	/// Adds two `VecN` together (by summing their members)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(VecN::new([1, 2, 3]) + VecN::new([3, 2, 1]), VecN::new([4, 4, 4]));
+	/// ```
+	add,
+
+	/// Adds two `VecN` together and stores the result into the first
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	let mut a = VecN::new([1, 2, 3]);
+	/// 	a += VecN::new([3, 2, 1]);
+	/// 	assert_eq!(a, VecN::new([4, 4, 4]));
+	/// ```
+	add_assign,
+
+	/// Subtracts one `VecN` from another (by subtracting their members)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(VecN::new([4, 4, 4]) - VecN::new([3, 2, 1]), VecN::new([1, 2, 3]));
+	/// ```
+	sub,
+
+	/// Subtracts one `VecN` from another and stores the result in the first
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	let mut a = VecN::new([4, 4, 4]);
+	/// 	a -= VecN::new([3, 2, 1]);
+	/// 	assert_eq!(a, VecN::new([1, 2, 3]));
+	/// ```
+	sub_assign,
+
+	/// Multiplies a `VecN` by it's scalar `T` (by multiplying it's members by the scalar)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(VecN::new([1, 2, 3]) * 2, VecN::new([2, 4, 6]));
+	/// ```
+	mul,
+
+	/// Multiplies a `VecN` by it's scalar `T` and stores the result into the `VecN`
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	let mut a = VecN::new([1, 2, 3]);
+	/// 	a *= 2;
+	/// 	assert_eq!(a, VecN::new([2, 4, 6]));
+	/// ```
+	mul_assign,
+
+	/// Divides a `VecN` by it's scalar `T` (by dividing it's members by the scalar)
+	/// Note: truncates by default if the underlying types truncate
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(VecN::new([2, 4, 7]) / 2, VecN::new([1, 2, 3]));
+	/// ```
+	div,
+
+	/// Divides a `VecN` by it's scalar `T` and stores the result into the `VecN`
+	/// Note: truncates by default if the underlying types truncate
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	let mut a = VecN::new([2, 4, 7]);
+	/// 	a /= 2;
+	/// 	assert_eq!(a, VecN::new([1, 2, 3]));
+	/// ```
+	div_assign,
+
+	/// Negates a `VecN` (by negating its members)
+	/// # Examples
+	/// ```
+	/// 	use phys2d::vecn::VecN;
+	/// 	assert_eq!(-VecN::new([1, -2, 3]), VecN::new([-1, 2, -3]));
+	/// ```
+	neg
+);